@@ -0,0 +1,139 @@
+use aoc_solution::{ParseError, Solution};
+use std::fmt;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 1;
+
+    type Model<'a> = Vec<&'a str>;
+    type Error = ParseError<ErrorKind>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<&str>, Self::Error> {
+        input
+            .lines()
+            .map(|l| {
+                if find_digits_in(l).is_empty() {
+                    Err(ParseError::at(input, l, ErrorKind::NoDigit))
+                } else {
+                    Ok(l)
+                }
+            })
+            .collect()
+    }
+
+    // lines whose digits are all spelled out have no calibration value yet
+    fn part1(lines: &Vec<&str>) -> usize {
+        lines.iter().filter_map(|l| parse_line_digits_only(l)).sum()
+    }
+
+    fn part2(lines: &Vec<&str>) -> usize {
+        lines.iter().filter_map(|l| parse_line(l)).sum()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ErrorKind {
+    NoDigit,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::NoDigit => write!(f, "no digit in line"),
+        }
+    }
+}
+
+pub const DIGITS_SPELLED_OUT: [&str; 10] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+pub fn find_digits_in(line: &str) -> Vec<(usize, usize)> {
+    let digits = DIGITS_SPELLED_OUT.iter().enumerate().skip(1);
+    let indices_of_spelled_out_digits: Vec<(usize, usize)> = digits
+        .flat_map(|(digit, spelling)| {
+            line.match_indices(spelling)
+                .map(move |(idx, _)| (idx, digit))
+        })
+        .collect();
+
+    let indices_of_normal_digits = find_numeric_digits_in(line);
+
+    let mut all_digits_with_indices =
+        [indices_of_spelled_out_digits, indices_of_normal_digits].concat();
+    all_digits_with_indices.sort_by_key(|(index, _digit)| *index);
+    all_digits_with_indices
+}
+
+pub fn find_numeric_digits_in(line: &str) -> Vec<(usize, usize)> {
+    let mut indices_of_normal_digits: Vec<(usize, usize)> = (1..=9)
+        .flat_map(|digit| {
+            line.match_indices(&digit.to_string())
+                .map(move |(index, _)| (index, digit))
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    indices_of_normal_digits.sort_by_key(|(index, _digit)| *index);
+    indices_of_normal_digits
+}
+
+pub fn calibration_value(digits_in_string: &[(usize, usize)]) -> Option<usize> {
+    let f = digits_in_string.first()?.1;
+    let l = digits_in_string.last()?.1;
+    Some(f * 10 + l)
+}
+
+fn parse_line_digits_only(line: &str) -> Option<usize> {
+    calibration_value(&find_numeric_digits_in(line))
+}
+
+fn parse_line(line: &str) -> Option<usize> {
+    calibration_value(&find_digits_in(line))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn examples() {
+        aoc_solution::check_examples::<Day01>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_line_parser() {
+        assert_eq!(parse_line("1abc2"), Some(12));
+        assert_eq!(parse_line("pqr3stu8vwx"), Some(38));
+        assert_eq!(parse_line("a1b2c3d4e5f"), Some(15));
+        assert_eq!(parse_line("treb7uchet"), Some(77));
+        assert_eq!(parse_line("two1nine"), Some(29));
+        assert_eq!(parse_line("eightwothree"), Some(83));
+        assert_eq!(parse_line("abcone2threexyz"), Some(13));
+        assert_eq!(parse_line("xtwone3four"), Some(24));
+        assert_eq!(parse_line("4nineeightseven2"), Some(42));
+        assert_eq!(parse_line("zoneight234"), Some(14));
+        assert_eq!(parse_line("7pqrstsixteen"), Some(76));
+        assert_eq!(parse_line("oneight"), Some(18));
+    }
+
+    #[test]
+    fn test_line_parser_digits_only() {
+        assert_eq!(parse_line_digits_only("1abc2"), Some(12));
+        assert_eq!(parse_line_digits_only("pqr3stu8vwx"), Some(38));
+        assert_eq!(parse_line_digits_only("a1b2c3d4e5f"), Some(15));
+        assert_eq!(parse_line_digits_only("treb7uchet"), Some(77));
+        assert_eq!(parse_line_digits_only("two1nine"), Some(11));
+        assert_eq!(parse_line_digits_only("eightwothree"), None);
+    }
+
+    #[test]
+    fn line_without_digit() {
+        let e = Day01::parse("two1nine\nabc\n").unwrap_err();
+        assert_eq!((e.line, e.column, e.kind), (2, 1, ErrorKind::NoDigit));
+        assert_eq!(e.text, "abc");
+    }
+}
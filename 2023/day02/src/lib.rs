@@ -0,0 +1,381 @@
+use nom::bytes::complete::tag;
+use nom::character::complete::{alpha1, space0, space1};
+use nom::combinator::all_consuming;
+use nom::multi::separated_list1;
+use nom::sequence::{delimited, terminated, tuple};
+
+use aoc_solution::parse::number;
+use aoc_solution::{ErrorAt, ParseError, Solution};
+use std::fmt;
+use std::num::ParseIntError;
+
+type IResult<I, O> = nom::IResult<I, O, ErrorAt<I, ErrorKind>>;
+
+pub const BAG: Bag = Bag {
+    red: 12,
+    green: 13,
+    blue: 14,
+};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 2;
+
+    type Model<'a> = Vec<Game>;
+    type Error = ParseError<ErrorKind>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Game>, Self::Error> {
+        input
+            .lines()
+            .map(|l| {
+                all_consuming(parse_game)(l)
+                    .map(|(_, g)| g)
+                    .map_err(|e| ParseError::from_nom(input, e))
+            })
+            .collect()
+    }
+
+    fn part1(games: &Vec<Game>) -> usize {
+        games
+            .iter()
+            .filter(|g| g.possible_given(&BAG))
+            .map(|g| g.id.0)
+            .sum()
+    }
+
+    fn part2(games: &Vec<Game>) -> usize {
+        games.iter().map(|g| minimum_bag(g).power()).sum()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ErrorKind {
+    UnknownColour,
+    InvalidNumber,
+    Unexpected,
+}
+
+impl From<nom::error::ErrorKind> for ErrorKind {
+    fn from(_: nom::error::ErrorKind) -> Self {
+        ErrorKind::Unexpected
+    }
+}
+
+impl From<ParseIntError> for ErrorKind {
+    fn from(_: ParseIntError) -> Self {
+        ErrorKind::InvalidNumber
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::UnknownColour => write!(f, "unknown colour"),
+            ErrorKind::InvalidNumber => write!(f, "invalid number"),
+            ErrorKind::Unexpected => write!(f, "unexpected input"),
+        }
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Handful {
+    pub red: usize,
+    pub green: usize,
+    pub blue: usize,
+}
+#[derive(Debug, PartialEq)]
+pub struct Bag {
+    pub red: usize,
+    pub green: usize,
+    pub blue: usize,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct GameId(usize);
+
+#[derive(Debug, PartialEq)]
+pub struct Game {
+    pub id: GameId,
+    pub handfuls: Vec<Handful>,
+}
+
+impl Handful {
+    pub fn possible_given(&self, bag: &Bag) -> bool {
+        self.red <= bag.red && self.green <= bag.green && self.blue <= bag.blue
+    }
+
+    pub fn add(&mut self, cubes: &BunchOfCubes) {
+        match cubes {
+            BunchOfCubes::Red(r) => self.red += r,
+            BunchOfCubes::Green(g) => self.green += g,
+            BunchOfCubes::Blue(b) => self.blue += b,
+        }
+    }
+}
+
+impl From<Vec<BunchOfCubes>> for Handful {
+    fn from(v: Vec<BunchOfCubes>) -> Self {
+        let mut hf = Handful::default();
+        for cubes in v {
+            hf.add(&cubes)
+        }
+        hf
+    }
+}
+
+impl Game {
+    pub fn possible_given(&self, bag: &Bag) -> bool {
+        self.handfuls.iter().all(|h| h.possible_given(bag))
+    }
+}
+
+impl Bag {
+    pub fn power(&self) -> usize {
+        self.red * self.green * self.blue
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum BunchOfCubes {
+    Red(usize),
+    Green(usize),
+    Blue(usize),
+}
+
+fn line_header(input: &str) -> IResult<&str, GameId> {
+    terminated(
+        tuple((tag("Game"), space1, number)),
+        delimited(space0, tag(":"), space0),
+    )(input)
+    .map(|(rest, (_, _, i))| (rest, GameId(i)))
+}
+
+fn parse_bunch_of_cubes(input: &str) -> IResult<&str, BunchOfCubes> {
+    delimited(space0, tuple((number, space1, alpha1)), space0)(input).and_then(
+        |(rest, (amount, _, colour))| {
+            let cubes = match colour {
+                "red" => BunchOfCubes::Red(amount),
+                "green" => BunchOfCubes::Green(amount),
+                "blue" => BunchOfCubes::Blue(amount),
+                _ => {
+                    return Err(nom::Err::Failure(ErrorAt::new(
+                        colour,
+                        ErrorKind::UnknownColour,
+                    )))
+                }
+            };
+            Ok((rest, cubes))
+        },
+    )
+}
+
+fn parse_handful_of_cubes(input: &str) -> IResult<&str, Handful> {
+    let (rest, vec) = separated_list1(tag(","), parse_bunch_of_cubes)(input)?;
+    Ok((rest, vec.into()))
+}
+
+fn parse_game(input: &str) -> IResult<&str, Game> {
+    let (rest, game_id) = line_header(input)?;
+    let (rest, vec) =
+        separated_list1(tag(";"), delimited(space0, parse_handful_of_cubes, space0))(rest)?;
+    Ok((
+        rest,
+        Game {
+            id: game_id,
+            handfuls: vec,
+        },
+    ))
+}
+
+pub fn minimum_bag(game: &Game) -> Bag {
+    let min_red = game.handfuls.iter().map(|h| h.red).max().unwrap();
+    let min_green = game.handfuls.iter().map(|h| h.green).max().unwrap();
+    let min_blue = game.handfuls.iter().map(|h| h.blue).max().unwrap();
+
+    Bag {
+        red: min_red,
+        green: min_green,
+        blue: min_blue,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn examples() {
+        aoc_solution::check_examples::<Day02>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn empty_handful_always_possible() {
+        let h0 = Handful {
+            red: 0,
+            green: 0,
+            blue: 0,
+        };
+        let b = Bag {
+            red: 0,
+            green: 0,
+            blue: 0,
+        };
+        assert!(h0.possible_given(&b));
+    }
+    #[test]
+    fn impossible_handful_given_in_problem_statement() {
+        let bag = Bag {
+            red: 12,
+            green: 13,
+            blue: 14,
+        };
+        let g4_h3 = Handful {
+            red: 14,
+            green: 3,
+            blue: 15,
+        };
+        assert!(!g4_h3.possible_given(&bag));
+    }
+
+    #[test]
+    fn boc_parser() {
+        assert_eq!(
+            parse_bunch_of_cubes("8 green"),
+            Ok(("", BunchOfCubes::Green(8)))
+        );
+        assert_eq!(
+            parse_bunch_of_cubes(" 8 green"),
+            Ok(("", BunchOfCubes::Green(8)))
+        );
+        assert_eq!(
+            parse_bunch_of_cubes("8 green     "),
+            Ok(("", BunchOfCubes::Green(8)))
+        );
+        assert_eq!(
+            parse_bunch_of_cubes("123 blue"),
+            Ok(("", BunchOfCubes::Blue(123)))
+        );
+    }
+
+    #[test]
+    fn hfparser() {
+        assert_eq!(
+            parse_handful_of_cubes("123 blue"),
+            Ok((
+                "",
+                Handful {
+                    red: 0,
+                    green: 0,
+                    blue: 123
+                }
+            ))
+        );
+        assert_eq!(
+            parse_handful_of_cubes("8 green, 6 blue, 20 red"),
+            Ok((
+                "",
+                Handful {
+                    red: 20,
+                    green: 8,
+                    blue: 6
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn game_parser() {
+        assert_eq!(
+            parse_game("Game 1 : 8 green, 6 blue, 20 red"),
+            Ok((
+                "",
+                Game {
+                    id: GameId(1),
+                    handfuls: vec![Handful {
+                        red: 20,
+                        green: 8,
+                        blue: 6
+                    }]
+                }
+            ))
+        );
+
+        assert_eq!(
+            parse_game("Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green"),
+            Ok((
+                "",
+                Game {
+                    id: GameId(1),
+                    handfuls: vec![
+                        Handful {
+                            red: 4,
+                            green: 0,
+                            blue: 3
+                        },
+                        Handful {
+                            red: 1,
+                            green: 2,
+                            blue: 6
+                        },
+                        Handful {
+                            red: 0,
+                            green: 2,
+                            blue: 0
+                        }
+                    ]
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        let input = "Game 1: 3 blue, 4 red\nGame 2: 1 red, 2 purple\n";
+        let e = Day02::parse(input).unwrap_err();
+        assert_eq!(
+            (e.line, e.column, e.text.as_str(), e.kind),
+            (2, 18, "purple", ErrorKind::UnknownColour)
+        );
+
+        let e = Day02::parse("Game 1: 3 blue; 99999999999999999999 red").unwrap_err();
+        assert_eq!((e.column, e.kind), (17, ErrorKind::InvalidNumber));
+
+        let e = Day02::parse("Game 1: 3 blue; and more").unwrap_err();
+        assert_eq!((e.column, e.kind), (15, ErrorKind::Unexpected));
+    }
+
+    #[test]
+    fn minbag() {
+        assert_eq!(
+            minimum_bag(&Game {
+                id: GameId(1),
+                handfuls: vec![
+                    Handful {
+                        red: 4,
+                        green: 0,
+                        blue: 3
+                    },
+                    Handful {
+                        red: 1,
+                        green: 2,
+                        blue: 6
+                    },
+                    Handful {
+                        red: 0,
+                        green: 2,
+                        blue: 0
+                    }
+                ]
+            }),
+            Bag {
+                red: 4,
+                green: 2,
+                blue: 6
+            }
+        )
+    }
+}
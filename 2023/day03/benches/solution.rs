@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use day03::Day03;
+
+fn main() -> ExitCode {
+    aoc_solution::bench::run::<Day03>(env!("CARGO_MANIFEST_DIR"))
+}
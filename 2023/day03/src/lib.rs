@@ -0,0 +1,283 @@
+use aoc_grid::{Grid, Point};
+use aoc_input::Malformed;
+use aoc_solution::parse::number;
+use aoc_solution::{ErrorAt, ParseError, Solution};
+use std::fmt;
+use std::num::ParseIntError;
+
+use nom::branch::alt;
+use nom::bytes::complete::tag;
+use nom::character::complete::anychar;
+use nom::multi::{many0, many1_count};
+use std::collections::HashMap;
+
+type IResult<I, O> = nom::IResult<I, O, ErrorAt<I, ErrorKind>>;
+
+pub type NumberStore = HashMap<XBoundingBox, usize>;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 3;
+
+    type Model<'a> = Schematic;
+    type Error = ParseError<ErrorKind>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Schematic, Self::Error> {
+        let mut schematic = Schematic {
+            grid: Grid::chars(input).map_err(|e| ParseError::locate(input, e))?,
+            numbers: NumberStore::new(),
+            symbols: Vec::new(),
+        };
+
+        for (y, line) in input.lines().enumerate() {
+            let entities = parse_line(line)
+                .map_err(|e| ParseError::from_nom(input, e))?
+                .1;
+            for entity in process_schematic_line(y, entities) {
+                match entity {
+                    SchematicEntity::Number {
+                        value,
+                        bounding_box,
+                    } => {
+                        schematic.numbers.insert(bounding_box, value);
+                    }
+                    SchematicEntity::Symbol(p, c) => schematic.symbols.push((p, c)),
+                }
+            }
+        }
+        Ok(schematic)
+    }
+
+    fn part1(schematic: &Schematic) -> usize {
+        let mut numbers_to_add_up: NumberStore = NumberStore::new();
+        for p in &schematic.symbols {
+            numbers_to_add_up.extend(schematic.adjacent_numbers(&p.0));
+        }
+        numbers_to_add_up.values().sum()
+    }
+
+    fn part2(schematic: &Schematic) -> usize {
+        let mut gear_ratios_sum = 0;
+        for p in &schematic.symbols {
+            let adjacent_numbers = schematic.adjacent_numbers(&p.0);
+            if p.1 == '*' && adjacent_numbers.len() == 2 {
+                let gear_ratio = adjacent_numbers.iter().fold(1, |acc, el| acc * el.1);
+                gear_ratios_sum += gear_ratio;
+            }
+        }
+        gear_ratios_sum
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ErrorKind {
+    Malformed(Malformed),
+    InvalidNumber,
+    Unexpected,
+}
+
+impl From<Malformed> for ErrorKind {
+    fn from(e: Malformed) -> Self {
+        ErrorKind::Malformed(e)
+    }
+}
+
+impl From<nom::error::ErrorKind> for ErrorKind {
+    fn from(_: nom::error::ErrorKind) -> Self {
+        ErrorKind::Unexpected
+    }
+}
+
+impl From<ParseIntError> for ErrorKind {
+    fn from(_: ParseIntError) -> Self {
+        ErrorKind::InvalidNumber
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::Malformed(e) => write!(f, "{}", e),
+            ErrorKind::InvalidNumber => write!(f, "invalid number"),
+            ErrorKind::Unexpected => write!(f, "unexpected input"),
+        }
+    }
+}
+
+pub struct Schematic {
+    pub grid: Grid<char>,
+    pub numbers: NumberStore,
+    pub symbols: Vec<(Point, char)>,
+}
+
+impl Schematic {
+    pub fn adjacent_numbers(&self, p: &Point) -> NumberStore {
+        let mut adjacent_numbers: NumberStore = NumberStore::new();
+        for neigh in self.grid.neighbours8(*p) {
+            for (bb, val) in &self.numbers {
+                if bb.contains(&neigh) {
+                    adjacent_numbers.insert(bb.clone(), *val);
+                }
+            }
+        }
+        adjacent_numbers
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct XBoundingBox {
+    pub y: usize,
+    pub x: (usize, usize),
+}
+
+impl XBoundingBox {
+    pub fn contains(&self, p: &Point) -> bool {
+        self.y == p.y && p.x >= self.x.0 && p.x <= self.x.1
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum SchematicEntity {
+    Number {
+        value: usize,
+        bounding_box: XBoundingBox,
+    },
+    Symbol(Point, char),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum LineEntity {
+    Spacing(usize),
+    Number(usize),
+    Symbol(char),
+}
+
+fn parse_spacing(input: &str) -> IResult<&str, LineEntity> {
+    many1_count(tag("."))(input).map(|(rest, n)| (rest, LineEntity::Spacing(n)))
+}
+fn parse_number(input: &str) -> IResult<&str, LineEntity> {
+    number(input).map(|(rest, n)| (rest, LineEntity::Number(n)))
+}
+fn parse_symbol(input: &str) -> IResult<&str, LineEntity> {
+    anychar(input).map(|(rest, c)| (rest, LineEntity::Symbol(c)))
+}
+
+fn parse_line(input: &str) -> IResult<&str, Vec<LineEntity>> {
+    many0(alt((parse_spacing, parse_number, parse_symbol)))(input)
+}
+
+fn process_schematic_line(line_y: usize, parsed_line: Vec<LineEntity>) -> Vec<SchematicEntity> {
+    let mut running_x: usize = 0;
+    let mut entities: Vec<SchematicEntity> = vec![];
+
+    for le in parsed_line {
+        match le {
+            LineEntity::Number(n) => {
+                let width = n.to_string().len();
+                entities.push(SchematicEntity::Number {
+                    value: n,
+                    bounding_box: XBoundingBox {
+                        y: line_y,
+                        x: (running_x, running_x + width - 1),
+                    },
+                });
+                running_x += width;
+            }
+            LineEntity::Spacing(n) => running_x += n,
+            LineEntity::Symbol(c) => {
+                entities.push(SchematicEntity::Symbol(
+                    Point {
+                        y: line_y,
+                        x: running_x,
+                    },
+                    c,
+                ));
+                running_x += 1;
+            }
+        }
+    }
+
+    entities
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn examples() {
+        aoc_solution::check_examples::<Day03>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn bounding_box() {
+        assert!(XBoundingBox { y: 2, x: (0, 10) }.contains(&Point { y: 2, x: 3 }));
+        assert!(XBoundingBox { y: 2, x: (0, 10) }.contains(&Point { y: 2, x: 10 }));
+        assert!(!XBoundingBox { y: 2, x: (0, 10) }.contains(&Point { y: 2, x: 11 }));
+        assert!(!XBoundingBox { y: 2, x: (0, 10) }.contains(&Point { y: 0, x: 11 }));
+    }
+
+    #[test]
+    fn spaceparser() {
+        assert_eq!(parse_spacing("."), Ok(("", LineEntity::Spacing(1))));
+        assert_eq!(parse_spacing("..."), Ok(("", LineEntity::Spacing(3))));
+    }
+
+    #[test]
+    fn parse_entity() {
+        assert_eq!(parse_line("."), Ok(("", vec![LineEntity::Spacing(1)])));
+        assert_eq!(
+            parse_line(".0..10....$"),
+            Ok((
+                "",
+                vec![
+                    LineEntity::Spacing(1),
+                    LineEntity::Number(0),
+                    LineEntity::Spacing(2),
+                    LineEntity::Number(10),
+                    LineEntity::Spacing(4),
+                    LineEntity::Symbol('$')
+                ]
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        let e = Day03::parse("......................\n.99999999999999999999.")
+            .err()
+            .unwrap();
+        assert_eq!(
+            (e.line, e.column, e.text.as_str(), e.kind),
+            (2, 2, "99999999999999999999", ErrorKind::InvalidNumber)
+        );
+
+        let e = Day03::parse("..12..\n.......\n").err().unwrap();
+        assert_eq!(
+            (e.line, e.column, e.kind),
+            (2, 1, ErrorKind::Malformed(Malformed::UnevenRows))
+        );
+    }
+
+    #[test]
+    fn processscl() {
+        assert_eq!(
+            process_schematic_line(0, parse_line(".0..10....$").unwrap().1),
+            vec![
+                SchematicEntity::Number {
+                    value: 0,
+                    bounding_box: XBoundingBox { y: 0, x: (1, 1) }
+                },
+                SchematicEntity::Number {
+                    value: 10,
+                    bounding_box: XBoundingBox { y: 0, x: (4, 5) }
+                },
+                SchematicEntity::Symbol(Point { y: 0, x: 10 }, '$')
+            ]
+        );
+    }
+}
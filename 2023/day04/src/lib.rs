@@ -0,0 +1,250 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::num::ParseIntError;
+
+use aoc_solution::parse::number;
+use aoc_solution::{ErrorAt, ParseError, Solution};
+
+use array_tool::vec::*;
+use nom::bytes::complete::tag;
+use nom::character::complete::{space0, space1};
+use nom::combinator::all_consuming;
+use nom::multi::separated_list1;
+use nom::sequence::{delimited, separated_pair, tuple};
+
+type IResult<I, O> = nom::IResult<I, O, ErrorAt<I, ErrorKind>>;
+
+use std::ops::Range;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 4;
+
+    type Model<'a> = SetOfCards;
+    type Error = ParseError<ErrorKind>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(input: &str) -> Result<SetOfCards, Self::Error> {
+        let cards: Vec<Card> = input
+            .lines()
+            .map(|line| {
+                all_consuming(parse_line)(line)
+                    .map(|(_, card)| card)
+                    .map_err(|e| ParseError::from_nom(input, e))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(cards.into())
+    }
+
+    fn part1(winning_cards: &SetOfCards) -> u32 {
+        winning_cards.points()
+    }
+
+    fn part2(winning_cards: &SetOfCards) -> u32 {
+        full_winnings_of_set(winning_cards)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ErrorKind {
+    InvalidNumber,
+    Unexpected,
+}
+
+impl From<nom::error::ErrorKind> for ErrorKind {
+    fn from(_: nom::error::ErrorKind) -> Self {
+        ErrorKind::Unexpected
+    }
+}
+
+impl From<ParseIntError> for ErrorKind {
+    fn from(_: ParseIntError) -> Self {
+        ErrorKind::InvalidNumber
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::InvalidNumber => write!(f, "invalid number"),
+            ErrorKind::Unexpected => write!(f, "unexpected input"),
+        }
+    }
+}
+
+fn parse_card_number(input: &str) -> IResult<&str, u16> {
+    delimited(
+        tuple((tag("Card"), space1)),
+        number,
+        tuple((space0, tag(":"))),
+    )(input)
+}
+
+fn parse_number_list(input: &str) -> IResult<&str, Vec<u8>> {
+    delimited(space0, separated_list1(space1, number), space0)(input)
+}
+
+#[derive(Debug)]
+pub struct SetOfCards {
+    pub full_set_size: u16,
+    pub winning_cards: HashMap<u16, CardStats>,
+}
+
+impl SetOfCards {
+    pub fn points(&self) -> u32 {
+        self.winning_cards.values().map(|p| p.points() as u32).sum()
+    }
+}
+
+pub fn full_winnings_of_single_card(
+    set: &SetOfCards,
+    cache: &mut HashMap<u16, u32>,
+    n: u16,
+) -> u32 {
+    match cache.get(&n).copied() {
+        Some(val) => val,
+        None => {
+            if let Some(card) = set.winning_cards.get(&n) {
+                let winnings = card
+                    .prize()
+                    .map(|k| 1 + full_winnings_of_single_card(set, cache, k))
+                    .sum();
+                cache.insert(n, winnings);
+                winnings
+            } else {
+                cache.insert(n, 0);
+                0
+            }
+        }
+    }
+}
+
+pub fn full_winnings_of_set(set: &SetOfCards) -> u32 {
+    let mut cache: HashMap<u16, u32> = HashMap::new();
+    let full_wins = set
+        .winning_cards
+        .iter()
+        .fold((&mut cache, 0_u32), |(c, winnings), card| {
+            let wins = full_winnings_of_single_card(set, c, *card.0);
+            (c, winnings + wins)
+        })
+        .1;
+    full_wins + set.full_set_size as u32
+}
+
+impl From<Vec<Card>> for SetOfCards {
+    fn from(cards: Vec<Card>) -> Self {
+        SetOfCards {
+            full_set_size: cards.len() as u16,
+            winning_cards: cards
+                .into_iter()
+                .map(|c| -> CardStats { c.into() })
+                .filter(|c| c.number_of_matching_cards != 0)
+                .map(|c| (c.number, c))
+                .collect(),
+        }
+    }
+}
+
+#[derive(PartialEq, Debug)]
+pub struct Card {
+    pub number: u16,
+    pub winning_numbers: Vec<u8>,
+    pub numbers_you_have: Vec<u8>,
+}
+
+impl From<(u16, (Vec<u8>, Vec<u8>))> for Card {
+    fn from(item: (u16, (Vec<u8>, Vec<u8>))) -> Self {
+        Card {
+            number: item.0,
+            winning_numbers: item.1 .0,
+            numbers_you_have: item.1 .1,
+        }
+    }
+}
+
+#[derive(PartialEq, Debug)]
+pub struct CardStats {
+    pub number: u16,
+    pub number_of_matching_cards: u16,
+}
+
+impl From<Card> for CardStats {
+    fn from(item: Card) -> Self {
+        CardStats {
+            number: item.number,
+            number_of_matching_cards: item
+                .winning_numbers
+                .intersect(item.numbers_you_have.clone())
+                .len() as u16,
+        }
+    }
+}
+
+impl CardStats {
+    pub fn points(&self) -> u16 {
+        let matching_numbers = self.number_of_matching_cards;
+        if matching_numbers == 0 {
+            return 0;
+        }
+        1 << (matching_numbers - 1)
+    }
+
+    pub fn prize(&self) -> Range<u16> {
+        self.number + 1..(self.number + 1 + self.number_of_matching_cards)
+    }
+}
+
+fn parse_line(input: &str) -> IResult<&str, Card> {
+    tuple((
+        parse_card_number,
+        separated_pair(
+            parse_number_list,
+            tuple((space0, tag("|"), space0)),
+            parse_number_list,
+        ),
+    ))(input)
+    .map(|(rest, tup)| (rest, tup.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn examples() {
+        aoc_solution::check_examples::<Day04>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_line_parser() {
+        assert_eq!(
+            parse_line("Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53"),
+            Ok((
+                "",
+                (
+                    1,
+                    (vec![41, 48, 83, 86, 17], vec![83, 86, 6, 31, 17, 9, 48, 53])
+                )
+                    .into()
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        let e = Day04::parse("Card 1: 41 48 | 83 86\nCard 2: 41 300 | 83 86")
+            .err()
+            .unwrap();
+        assert_eq!(
+            (e.line, e.column, e.text.as_str(), e.kind),
+            (2, 12, "300", ErrorKind::InvalidNumber)
+        );
+
+        let e = Day04::parse("Card 1: 41 48 83 86").err().unwrap();
+        assert_eq!((e.line, e.column, e.kind), (1, 20, ErrorKind::Unexpected));
+    }
+}
@@ -0,0 +1,1135 @@
+use nom::bytes::complete::tag;
+use nom::character::complete::{alpha1, char, multispace0, multispace1, newline, space0, space1};
+use nom::combinator::eof;
+use nom::multi::{many1, separated_list1};
+use nom::sequence::{delimited, preceded, separated_pair, terminated, tuple};
+
+use core::ops::Range;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+use std::num::ParseIntError;
+
+use aoc_rangeset::RangeSet;
+use aoc_solution::parse::number;
+use aoc_solution::{ErrorAt, ParseError, Solution};
+
+type IResult<I, O> = nom::IResult<I, O, ErrorAt<I, ErrorKind>>;
+
+use itertools::Itertools;
+
+pub mod lint;
+
+use lint::Lint;
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 5;
+
+    type Model<'a> = Garden<'a>;
+    type Error = ParseError<ErrorKind>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    // Fails when no maps lead from seeds to locations, and when the seeds do
+    // not make at least one range for part 2, on top of what `parse_linted`
+    // fails on.
+    fn parse(input: &str) -> Result<Garden<'_>, Self::Error> {
+        let (seeds, almanac) = parse_linted(input)?;
+        let seeds_line = input.lines().next().unwrap_or_default();
+        if seeds.len() % 2 != 0 {
+            let last = seeds_line.split_ascii_whitespace().last().unwrap();
+            return Err(ParseError::at(input, last, ErrorKind::UnpairedSeed));
+        }
+        if reinterpret_seed_list_as_ranges(seeds.clone())
+            .iter()
+            .all(|r| r.is_empty())
+        {
+            return Err(ParseError::at(input, seeds_line, ErrorKind::NoSeedRange));
+        }
+        let seed_to_location = almanac
+            .between("seed", "location")
+            .map_err(|e| ParseError::at(input, &input[input.len()..], ErrorKind::NoChain(e)))?;
+        Ok(Garden {
+            seeds,
+            almanac,
+            seed_to_location,
+        })
+    }
+
+    fn part1(garden: &Garden<'_>) -> u64 {
+        let map = &garden.seed_to_location;
+        garden
+            .seeds
+            .iter()
+            .map(|seed| map.remap(seed))
+            .min()
+            .unwrap()
+    }
+
+    fn part2(garden: &Garden<'_>) -> u64 {
+        let map = &garden.seed_to_location;
+        reinterpret_seed_list_as_ranges(garden.seeds.clone())
+            .into_iter()
+            .flat_map(|r| map.remap_range(r))
+            .map(|r| r.start)
+            .min()
+            .unwrap()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ErrorKind {
+    InvalidNumber,
+    Unexpected,
+    Invalid(Lint),
+    NoChain(PathError),
+    UnpairedSeed,
+    NoSeedRange,
+}
+
+impl From<nom::error::ErrorKind> for ErrorKind {
+    fn from(_: nom::error::ErrorKind) -> Self {
+        ErrorKind::Unexpected
+    }
+}
+
+impl From<ParseIntError> for ErrorKind {
+    fn from(_: ParseIntError) -> Self {
+        ErrorKind::InvalidNumber
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::InvalidNumber => write!(f, "invalid number"),
+            ErrorKind::Unexpected => write!(f, "unexpected input"),
+            ErrorKind::Invalid(lint) => write!(f, "{}", lint),
+            ErrorKind::NoChain(e) => write!(f, "{}", e),
+            ErrorKind::UnpairedSeed => write!(f, "seed without a range length"),
+            ErrorKind::NoSeedRange => write!(f, "every seed range is empty"),
+        }
+    }
+}
+
+// The seeds and almanac of an input, along with the map from seeds to
+// locations the parts look them up in.
+#[derive(Debug)]
+pub struct Garden<'a> {
+    pub seeds: Vec<u64>,
+    pub almanac: Almanac<'a>,
+    pub seed_to_location: Map<'a>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum PathError {
+    UnknownCategory(String),
+    NoPath { from: String, to: String },
+}
+
+impl fmt::Display for PathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathError::UnknownCategory(name) => write!(f, "no `{}` category in the almanac", name),
+            PathError::NoPath { from, to } => {
+                write!(f, "no chain of maps from `{}` to `{}`", from, to)
+            }
+        }
+    }
+}
+
+impl std::error::Error for PathError {}
+
+pub fn reinterpret_seed_list_as_ranges(list: Vec<u64>) -> Vec<Range<u64>> {
+    list.into_iter()
+        .tuple_windows::<(_, _)>()
+        .step_by(2)
+        .map(|(a, b)| a..a.saturating_add(b))
+        .collect::<Vec<_>>()
+}
+
+// An entry as written in the almanac. Its ranges are cut short at `u64::MAX`
+// if they would go past it, which the lints report.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapEntry {
+    pub destination_range_start: u64,
+    pub source_range_start: u64,
+    pub range_size: u64,
+}
+
+impl MapEntry {
+    pub fn source_range(&self) -> Range<u64> {
+        self.source_range_start..self.source_range_start.saturating_add(self.range_size)
+    }
+
+    pub fn destination_range(&self) -> Range<u64> {
+        self.destination_range_start..self.destination_range_start.saturating_add(self.range_size)
+    }
+
+    // How far the entry moves numbers, unless one of its ranges overflows.
+    pub fn segment(&self) -> Option<Segment> {
+        self.source_range_start.checked_add(self.range_size)?;
+        self.destination_range_start.checked_add(self.range_size)?;
+        Some(Segment {
+            source: self.source_range(),
+            offset: self.destination_range_start as i128 - self.source_range_start as i128,
+        })
+    }
+
+    pub fn remap(&self, n: &u64) -> Option<u64> {
+        if !self.source_range().contains(n) {
+            return None;
+        }
+
+        self.destination_range_start
+            .checked_add(n - self.source_range_start)
+    }
+
+    // The number this entry sends to `n`, if any.
+    pub fn unmap(&self, n: &u64) -> Option<u64> {
+        if !self.destination_range().contains(n) {
+            return None;
+        }
+
+        self.source_range_start
+            .checked_add(n - self.destination_range_start)
+    }
+
+    // The numbers this entry sends into `r`.
+    pub fn unmap_range(&self, r: Range<u64>) -> Option<Range<u64>> {
+        let hit = RangeSet::from(r)
+            .intersection(&RangeSet::from(self.destination_range()))
+            .into_ranges()
+            .pop()?;
+        Some(self.unmap(&hit.start)?..self.unmap(&(hit.end - 1))? + 1)
+    }
+
+    // The part of `r` this entry moves, remapped, and the parts it leaves.
+    pub fn remap_range(&self, r: Range<u64>) -> Option<(Range<u64>, Vec<Range<u64>>)> {
+        let r = RangeSet::from(r);
+        let source = RangeSet::from(self.source_range());
+        let moved = r.intersection(&source).into_ranges().pop()?;
+        let remapped = self.remap(&moved.start)?..self.remap(&(moved.end - 1))? + 1;
+        Some((remapped, r.difference(&source).into_ranges()))
+    }
+}
+
+impl fmt::Display for MapEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {}",
+            self.destination_range_start, self.source_range_start, self.range_size
+        )
+    }
+}
+
+// A range of numbers a map moves by the same signed offset, which keeps them
+// within `u64`. Only made from entries that do not overflow, and from cuts of
+// other segments, so that this holds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Segment {
+    source: Range<u64>,
+    offset: i128,
+}
+
+impl Segment {
+    pub fn source(&self) -> Range<u64> {
+        self.source.clone()
+    }
+
+    pub fn offset(&self) -> i128 {
+        self.offset
+    }
+
+    pub fn apply(&self, n: u64) -> u64 {
+        u64::try_from(n as i128 + self.offset)
+            .unwrap_or_else(|_| panic!("{} moved by {} leaves u64", n, self.offset))
+    }
+
+    // The number `apply` sends to `n`.
+    pub fn unapply(&self, n: u64) -> u64 {
+        u64::try_from(n as i128 - self.offset)
+            .unwrap_or_else(|_| panic!("{} moved back by {} leaves u64", n, self.offset))
+    }
+
+    pub fn destination(&self) -> Range<u64> {
+        self.apply(self.source.start)..self.apply(self.source.end)
+    }
+}
+
+// Sorts segments that do not overlap, fills the gaps between them with
+// identity ones so that they cover every number below `u64::MAX`, and merges
+// neighbours moving numbers by the same offset.
+fn normalize(mut segments: Vec<Segment>) -> Vec<Segment> {
+    segments.sort_by_key(|s| s.source.start);
+    let mut normalized: Vec<Segment> = vec![];
+    let mut start = 0;
+    let identity = |source: Range<u64>| Segment { source, offset: 0 };
+    for segment in segments.into_iter().chain([identity(u64::MAX..u64::MAX)]) {
+        for s in [identity(start..segment.source.start), segment] {
+            start = start.max(s.source.end);
+            match normalized.last_mut() {
+                _ if s.source.is_empty() => {}
+                Some(last) if last.offset == s.offset && last.source.end == s.source.start => {
+                    last.source.end = s.source.end;
+                }
+                _ => normalized.push(s),
+            }
+        }
+    }
+    normalized
+}
+
+// A map keeps its entries as written, and looks numbers up in the segments
+// they make: sorted, covering every number below `u64::MAX`, an entry only
+// taking what earlier ones left.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Map<'a> {
+    pub source_name: &'a str,
+    pub destination_name: &'a str,
+    entries: Vec<MapEntry>,
+    segments: Vec<Segment>,
+}
+
+impl<'a> Map<'a> {
+    // Entries that overflow are left out of the segments.
+    pub fn new(source_name: &'a str, destination_name: &'a str, entries: Vec<MapEntry>) -> Self {
+        let mut segments: Vec<_> = entries.iter().filter_map(MapEntry::segment).collect();
+        segments.sort_by_key(|s| s.source.start);
+        if segments
+            .windows(2)
+            .any(|w| w[0].source.end > w[1].source.start)
+        {
+            // entries overlap: each only takes what earlier ones left
+            let mut taken = RangeSet::new();
+            segments.clear();
+            for segment in entries.iter().filter_map(MapEntry::segment) {
+                let source = RangeSet::from(segment.source.clone());
+                for source in source.difference(&taken).into_ranges() {
+                    segments.push(Segment {
+                        source,
+                        offset: segment.offset,
+                    });
+                }
+                taken = taken.union(&source);
+            }
+        }
+        Self {
+            source_name,
+            destination_name,
+            entries,
+            segments: normalize(segments),
+        }
+    }
+
+    pub fn entries(&self) -> &[MapEntry] {
+        &self.entries
+    }
+
+    pub fn segments(&self) -> &[Segment] {
+        &self.segments
+    }
+
+    // The segment `n` is in, found by binary search; none for `u64::MAX`.
+    fn segment(&self, n: u64) -> Option<&Segment> {
+        let i = self
+            .segments
+            .partition_point(|s| s.source.start <= n)
+            .checked_sub(1)?;
+        Some(&self.segments[i]).filter(|s| s.source.contains(&n))
+    }
+
+    pub fn remap(&self, n: &u64) -> u64 {
+        self.segment(*n).map_or(*n, |s| s.apply(*n))
+    }
+
+    // How `r` is moved: the parts it is cut into, in order, each with the
+    // offset of its segment.
+    fn cuts(&self, r: Range<u64>) -> Vec<(Range<u64>, i128)> {
+        let first = self.segments.partition_point(|s| s.source.end <= r.start);
+        let mut cuts = vec![];
+        let mut start = r.start;
+        for s in &self.segments[first..] {
+            if start >= r.end {
+                break;
+            }
+            let end = r.end.min(s.source.end);
+            cuts.push((start..end, s.offset));
+            start = end;
+        }
+        cuts
+    }
+
+    // `remap` for a whole range, which comes out as the parts it is cut
+    // into, in the order of the source.
+    pub fn remap_range(&self, r: Range<u64>) -> Vec<Range<u64>> {
+        self.cuts(r)
+            .into_iter()
+            .map(|(cut, offset)| {
+                Segment {
+                    source: cut,
+                    offset,
+                }
+                .destination()
+            })
+            .collect()
+    }
+
+    // Every number the map sends to `n`: several entries can lead to it, and
+    // it can be left as it is too.
+    pub fn unmap(&self, n: &u64) -> Vec<u64> {
+        let mut sources: Vec<_> = self
+            .segments
+            .iter()
+            .filter(|s| s.destination().contains(n))
+            .map(|s| s.unapply(*n))
+            .collect();
+        sources.sort();
+        sources
+    }
+
+    // Every number the map sends into `r`.
+    pub fn unmap_range(&self, r: Range<u64>) -> RangeSet<u64> {
+        let r = RangeSet::from(r);
+        self.segments
+            .iter()
+            .flat_map(|s| {
+                r.intersection(&RangeSet::from(s.destination()))
+                    .into_ranges()
+                    .into_iter()
+                    .map(|hit| s.unapply(hit.start)..s.unapply(hit.end))
+            })
+            .collect()
+    }
+}
+
+// In the almanac format, the header then an entry per line.
+impl fmt::Display for Map<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-to-{} map:", self.source_name, self.destination_name)?;
+        for entry in &self.entries {
+            write!(f, "\n{}", entry)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub struct Almanac<'a>(Vec<Map<'a>>);
+
+impl<'a> Almanac<'a> {
+    pub fn location_of_seeds(&self, seeds: Vec<u64>) -> Vec<u64> {
+        self.0.iter().fold(seeds, |acc, map| {
+            acc.into_iter().map(|n| map.remap(&n)).collect()
+        })
+    }
+
+    // All the maps as one, from the first source to the last destination,
+    // with an entry per segment moving numbers.
+    pub fn compose(&self) -> Option<Map<'a>> {
+        let (first, last) = (self.0.first()?, self.0.last()?);
+
+        let mut segments = first.segments.clone();
+        for map in &self.0[1..] {
+            segments = segments
+                .iter()
+                .flat_map(|s| {
+                    map.cuts(s.destination())
+                        .into_iter()
+                        .map(|(cut, offset)| Segment {
+                            source: s.unapply(cut.start)..s.unapply(cut.end),
+                            offset: s.offset + offset,
+                        })
+                })
+                .collect();
+        }
+
+        let entries = normalize(segments)
+            .into_iter()
+            .filter(|s| s.offset != 0)
+            .map(|s| MapEntry {
+                destination_range_start: s.destination().start,
+                source_range_start: s.source.start,
+                range_size: s.source.end - s.source.start,
+            })
+            .collect();
+        Some(Map::new(first.source_name, last.destination_name, entries))
+    }
+
+    // Every category a map goes from or to, in the order they appear.
+    pub fn categories(&self) -> Vec<&'a str> {
+        let mut categories = vec![];
+        for map in &self.0 {
+            for name in [map.source_name, map.destination_name] {
+                if !categories.contains(&name) {
+                    categories.push(name);
+                }
+            }
+        }
+        categories
+    }
+
+    // The shortest chain of maps leading from one category to another,
+    // whatever order they are listed in.
+    pub fn path(&self, from: &str, to: &str) -> Result<Almanac<'a>, PathError> {
+        let categories = self.categories();
+        for name in [from, to] {
+            if !categories.contains(&name) {
+                return Err(PathError::UnknownCategory(name.to_owned()));
+            }
+        }
+
+        // breadth-first, remembering the map each category was reached by
+        let mut reached_by: HashMap<&str, Option<&Map<'a>>> = HashMap::from([(from, None)]);
+        let mut queue = VecDeque::from([from]);
+        while let Some(category) = queue.pop_front() {
+            if category == to {
+                break;
+            }
+            for map in self.0.iter().filter(|m| m.source_name == category) {
+                if !reached_by.contains_key(map.destination_name) {
+                    reached_by.insert(map.destination_name, Some(map));
+                    queue.push_back(map.destination_name);
+                }
+            }
+        }
+
+        let mut maps = vec![];
+        let mut category = to;
+        while let Some(map) = reached_by.get(category).ok_or(PathError::NoPath {
+            from: from.to_owned(),
+            to: to.to_owned(),
+        })? {
+            maps.push((*map).clone());
+            category = map.source_name;
+        }
+        maps.reverse();
+        Ok(Almanac(maps))
+    }
+
+    // The chain from one category to another composed into a single map.
+    pub fn between(&self, from: &'a str, to: &'a str) -> Result<Map<'a>, PathError> {
+        let path = self.path(from, to)?;
+        Ok(path.compose().unwrap_or_else(|| Map::new(from, to, vec![])))
+    }
+
+    pub fn range_of_locations_of_seeds(&self, seeds: Vec<Range<u64>>) -> Vec<Range<u64>> {
+        self.0.iter().fold(seeds, |acc, map| {
+            acc.into_iter().flat_map(|n| map.remap_range(n)).collect()
+        })
+    }
+
+    // The seeds that end up at a location.
+    pub fn seeds_of_location(&self, location: u64) -> Vec<u64> {
+        let mut seeds = self.0.iter().rev().fold(vec![location], |acc, map| {
+            acc.iter().flat_map(|n| map.unmap(n)).collect()
+        });
+        seeds.sort();
+        seeds.dedup();
+        seeds
+    }
+
+    // The seeds that end up in any of the location ranges.
+    pub fn range_of_seeds_of_locations(&self, locations: Vec<Range<u64>>) -> RangeSet<u64> {
+        self.0.iter().rev().fold(
+            locations.into_iter().collect(),
+            |acc: RangeSet<u64>, map| {
+                acc.ranges()
+                    .iter()
+                    .flat_map(|r| map.unmap_range(r.clone()).into_ranges())
+                    .collect()
+            },
+        )
+    }
+}
+
+// In the almanac format, the maps separated by blank lines.
+impl fmt::Display for Almanac<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, map) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, "\n\n")?;
+            }
+            write!(f, "{}", map)?;
+        }
+        Ok(())
+    }
+}
+
+// A whole input: the seeds, then the almanac.
+pub struct Listing<'s, 'a> {
+    pub seeds: &'s [u64],
+    pub almanac: &'s Almanac<'a>,
+}
+
+impl fmt::Display for Listing<'_, '_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "seeds:")?;
+        for seed in self.seeds {
+            write!(f, " {}", seed)?;
+        }
+        writeln!(f, "\n\n{}", self.almanac)
+    }
+}
+
+impl<'a> From<Vec<Map<'a>>> for Almanac<'a> {
+    fn from(v: Vec<Map<'a>>) -> Self {
+        Self(v)
+    }
+}
+
+fn parse_map_header(input: &str) -> IResult<&str, (&str, &str)> {
+    terminated(
+        separated_pair(alpha1, tag("-to-"), alpha1),
+        tuple((space1, tag("map"), space0, char(':'))),
+    )(input)
+}
+
+fn parse_map_entry(input: &str) -> IResult<&str, MapEntry> {
+    delimited(
+        space0,
+        tuple((
+            terminated(number, space1),
+            terminated(number, space1),
+            terminated(number, space0),
+        )),
+        space0,
+    )(input)
+    .map(|(rest, (a, b, c))| {
+        (
+            rest,
+            MapEntry {
+                destination_range_start: a,
+                source_range_start: b,
+                range_size: c,
+            },
+        )
+    })
+}
+
+fn parse_map<'a>(input: &'a str) -> IResult<&'a str, Map<'a>> {
+    tuple((
+        delimited(multispace0, parse_map_header, newline),
+        separated_list1(newline, parse_map_entry),
+    ))(input)
+    .map(|(rest, ((s, d), entries))| (rest, Map::new(s, d, entries)))
+}
+
+fn parse_seeds_to_plant(input: &str) -> IResult<&str, Vec<u64>> {
+    preceded(tag("seeds:"), many1(delimited(space0, number, space0)))(input)
+}
+
+// The seeds and almanac as written, before any lint.
+pub fn parse_unchecked(input: &str) -> Result<(Vec<u64>, Almanac<'_>), ParseError<ErrorKind>> {
+    parse_almanac(input)
+        .map(|(_, almanac)| almanac)
+        .map_err(|e| ParseError::from_nom(input, e))
+}
+
+// Fails on the first error the lints find, so that nothing gets looked up in
+// an ambiguous almanac.
+pub fn parse_linted(input: &str) -> Result<(Vec<u64>, Almanac<'_>), ParseError<ErrorKind>> {
+    let (seeds, almanac) = parse_unchecked(input)?;
+    if let Some(e) = lint::lint_almanac(input, &almanac)
+        .into_iter()
+        .find(|e| e.kind.is_error())
+    {
+        return Err(ParseError {
+            line: e.line,
+            column: e.column,
+            text: e.text,
+            kind: ErrorKind::Invalid(e.kind),
+        });
+    }
+    Ok((seeds, almanac))
+}
+
+fn parse_almanac(input: &str) -> IResult<&str, (Vec<u64>, Almanac<'_>)> {
+    separated_pair(
+        parse_seeds_to_plant,
+        newline,
+        terminated(
+            separated_list1(multispace1, parse_map),
+            tuple((multispace0, eof)),
+        ),
+    )(input)
+    .map(|(rest, (seeds, maps))| (rest, (seeds, maps.into())))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use crate::*;
+
+    fn example() -> Garden<'static> {
+        Day05::parse(include_str!("../example.txt")).unwrap()
+    }
+
+    #[test]
+    fn examples() {
+        aoc_solution::check_examples::<Day05>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn test_map_hearder_parser() {
+        assert_eq!(
+            parse_map_header("seed-to-soil map:"),
+            Ok(("", ("seed", "soil")))
+        );
+    }
+
+    #[test]
+    fn test_map_entry_parser() {
+        assert_eq!(
+            parse_map_entry("50 98 2"),
+            Ok((
+                "",
+                MapEntry {
+                    destination_range_start: 50,
+                    source_range_start: 98,
+                    range_size: 2,
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_map_parser() {
+        assert_eq!(
+            parse_map(
+                "seed-to-soil map:
+            50 98 2
+            52 50 48"
+            ),
+            Ok((
+                "",
+                Map::new(
+                    "seed",
+                    "soil",
+                    vec![
+                        MapEntry {
+                            destination_range_start: 50,
+                            source_range_start: 98,
+                            range_size: 2,
+                        },
+                        MapEntry {
+                            destination_range_start: 52,
+                            source_range_start: 50,
+                            range_size: 48,
+                        }
+                    ]
+                )
+            ))
+        );
+    }
+    #[test]
+    fn test_parse_seeds_to_plant() {
+        assert_eq!(
+            parse_seeds_to_plant("seeds: 79 14 55 13"),
+            Ok(("", vec![79, 14, 55, 13]))
+        );
+    }
+
+    #[test]
+    fn test_parse_full_problem() {
+        assert_eq!(
+            parse_almanac(
+                "seeds: 79 14 55 13
+            
+                seed-to-soil map:
+                50 98 2
+                52 50 48
+
+                seed-to-soil map:
+            50 98 2
+            52 50 48"
+            ),
+            Ok((
+                "",
+                (
+                    vec![79, 14, 55, 13],
+                    vec![
+                        Map::new(
+                            "seed",
+                            "soil",
+                            vec![
+                                MapEntry {
+                                    destination_range_start: 50,
+                                    source_range_start: 98,
+                                    range_size: 2,
+                                },
+                                MapEntry {
+                                    destination_range_start: 52,
+                                    source_range_start: 50,
+                                    range_size: 48,
+                                }
+                            ]
+                        ),
+                        Map::new(
+                            "seed",
+                            "soil",
+                            vec![
+                                MapEntry {
+                                    destination_range_start: 50,
+                                    source_range_start: 98,
+                                    range_size: 2,
+                                },
+                                MapEntry {
+                                    destination_range_start: 52,
+                                    source_range_start: 50,
+                                    range_size: 48,
+                                }
+                            ]
+                        )
+                    ]
+                    .into()
+                )
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        let e = Day05::parse("seeds: 79 14\n\nseed-to-soil map:\n50 98 x\n")
+            .err()
+            .unwrap();
+        assert_eq!((e.line, e.column, e.text.as_str()), (4, 7, "x"));
+
+        let e = Day05::parse("seeds: 1 2\n\nseed-to-soil map:\n0 10 5\n").unwrap_err();
+        assert_eq!(
+            e.kind,
+            ErrorKind::NoChain(PathError::UnknownCategory("location".to_owned()))
+        );
+        assert_eq!((e.line, e.text.as_str()), (5, ""));
+
+        let e = Day05::parse("seeds: 79 14 55\n\nseed-to-location map:\n0 10 5\n").unwrap_err();
+        assert_eq!(
+            (e.line, e.column, e.text.as_str(), e.kind),
+            (1, 14, "55", ErrorKind::UnpairedSeed)
+        );
+        let e = Day05::parse("seeds: 79 0\n\nseed-to-location map:\n0 10 5\n").unwrap_err();
+        assert_eq!((e.line, e.kind), (1, ErrorKind::NoSeedRange));
+        assert!(Day05::parse("seeds: 79 0 3 1\n\nseed-to-location map:\n0 10 5\n").is_ok());
+
+        let e = Day05::parse("seeds: 79 99999999999999999999999\n")
+            .err()
+            .unwrap();
+        assert_eq!(
+            (e.line, e.column, e.kind),
+            (1, 11, ErrorKind::InvalidNumber)
+        );
+    }
+
+    #[test]
+    fn solves_example() {
+        let example_almanac = "seeds: 79 14 55 13
+
+        seed-to-soil map:
+        50 98 2
+        52 50 48
+        
+        soil-to-fertilizer map:
+        0 15 37
+        37 52 2
+        39 0 15
+        
+        fertilizer-to-water map:
+        49 53 8
+        0 11 42
+        42 0 7
+        57 7 4
+        
+        water-to-light map:
+        88 18 7
+        18 25 70
+        
+        light-to-temperature map:
+        45 77 23
+        81 45 19
+        68 64 13
+        
+        temperature-to-humidity map:
+        0 69 1
+        1 0 69
+        
+        humidity-to-location map:
+        60 56 37
+        56 93 4";
+
+        let (seeds, almanac) = parse_almanac(example_almanac).unwrap().1;
+
+        let locations_pt1 = almanac.location_of_seeds(seeds.clone());
+        assert_eq!(locations_pt1, vec![82, 43, 86, 35]);
+
+        let seeds_pt2 = reinterpret_seed_list_as_ranges(seeds);
+        assert_eq!(seeds_pt2.into_iter().flatten().count(), 27);
+    }
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    fn composes() {
+        let almanac = example().almanac;
+        let map = almanac.compose().unwrap();
+        assert_eq!(
+            (map.source_name, map.destination_name),
+            ("seed", "location")
+        );
+        assert!(map
+            .entries()
+            .windows(2)
+            .all(|w| w[0].source_range().end <= w[1].source_range_start));
+
+        let seeds: Vec<_> = (0..120).collect();
+        let composed: Vec<_> = seeds.iter().map(|&n| map.remap(&n)).collect();
+        assert_eq!(composed, almanac.location_of_seeds(seeds));
+
+        let mut by_range: Vec<_> = map.remap_range(0..120).into_iter().flatten().collect();
+        let mut by_steps: Vec<_> = almanac
+            .range_of_locations_of_seeds(vec![0..120])
+            .into_iter()
+            .flatten()
+            .collect();
+        by_range.sort();
+        by_steps.sort();
+        assert_eq!(by_range, by_steps);
+
+        let printed = map.to_string();
+        assert!(printed.starts_with("seed-to-location map:\n"));
+        let (_, reparsed) = parse_map(&printed).unwrap();
+        assert_eq!(reparsed, map);
+    }
+
+    #[test]
+    fn paths() {
+        let almanac = example().almanac;
+        assert_eq!(almanac.categories().len(), 8);
+
+        let path = almanac.path("soil", "humidity").unwrap();
+        let names: Vec<_> = path.0.iter().map(|m| m.destination_name).collect();
+        assert_eq!(
+            names,
+            vec!["fertilizer", "water", "light", "temperature", "humidity"]
+        );
+        let map = almanac.between("soil", "humidity").unwrap();
+        let soils: Vec<_> = (0..100).collect();
+        let humidities: Vec<_> = soils.iter().map(|&n| map.remap(&n)).collect();
+        assert_eq!(humidities, path.location_of_seeds(soils));
+
+        let mut shuffled = almanac.clone();
+        shuffled.0.reverse();
+        assert_eq!(
+            shuffled.between("seed", "location").unwrap(),
+            almanac.compose().unwrap()
+        );
+        assert_eq!(almanac.between("light", "light").unwrap().entries(), &[]);
+        assert_eq!(
+            almanac.path("location", "seed"),
+            Err(PathError::NoPath {
+                from: "location".to_owned(),
+                to: "seed".to_owned()
+            })
+        );
+        assert_eq!(
+            almanac.path("seed", "planet"),
+            Err(PathError::UnknownCategory("planet".to_owned()))
+        );
+    }
+
+    #[test]
+    fn paths_without_locations() {
+        let input = "seeds: 1 2
+
+soil-to-water map:
+10 0 5
+
+water-to-humidity map:
+0 10 3
+";
+        assert!(matches!(
+            Day05::parse(input).unwrap_err().kind,
+            ErrorKind::NoChain(_)
+        ));
+        let (_, almanac) = parse_linted(input).unwrap();
+        let map = almanac.between("soil", "humidity").unwrap();
+        let humidities: Vec<_> = (0..6).map(|n| map.remap(&n)).collect();
+        assert_eq!(humidities, vec![0, 1, 2, 13, 14, 5]);
+
+        let overlapping = "seeds: 1\n\nsoil-to-water map:\n0 10 5\n20 12 5\n";
+        assert!(parse_linted(overlapping).is_err());
+    }
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    fn reverse_lookups() {
+        let Garden { seeds, almanac, .. } = example();
+        for (&seed, location) in seeds.iter().zip(almanac.location_of_seeds(seeds.clone())) {
+            assert!(almanac.seeds_of_location(location).contains(&seed));
+        }
+        for location in 0..100 {
+            for seed in almanac.seeds_of_location(location) {
+                assert_eq!(almanac.location_of_seeds(vec![seed]), vec![location]);
+            }
+        }
+
+        // the seeds of part 2 ending up below 47 are the one found at 46
+        let seeds: RangeSet<u64> = reinterpret_seed_list_as_ranges(seeds).into_iter().collect();
+        let found = almanac.range_of_seeds_of_locations(vec![0..47]);
+        assert_eq!(seeds.intersection(&found), RangeSet::from(82..83));
+
+        // whatever the seed ranges lead to leads back to them, among others
+        let locations = almanac.range_of_locations_of_seeds(seeds.ranges().to_vec());
+        let back = almanac.range_of_seeds_of_locations(locations);
+        assert_eq!(back.intersection(&seeds), seeds);
+    }
+
+    #[test]
+    fn round_trips() {
+        for file in ["example.txt", "input.txt"] {
+            let path = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join(file);
+            let input = aoc_solution::read_input(&path).unwrap();
+            let Garden { seeds, almanac, .. } = Day05::parse(&input).unwrap();
+            let listing = Listing {
+                seeds: &seeds,
+                almanac: &almanac,
+            };
+            assert_eq!(listing.to_string(), input);
+        }
+
+        // almanacs the lints reject print back as written too: overlapping,
+        // empty and overflowing entries, numbers near the top
+        for input in [
+            "seeds: 0\n\nseed-to-soil map:\n0 0 0\n",
+            "seeds: 7 18446744073709551615 7\n\n\
+             seed-to-soil map:\n\
+             50 0 10\n\
+             60 5 10\n\
+             0 18446744073709551610 20\n\
+             18446744073709551600 3 100\n\n\
+             soil-to-water map:\n\
+             10 99 18446744073709551615\n\
+             5 5 0\n",
+        ] {
+            let (seeds, almanac) = parse_unchecked(input).unwrap();
+            let listing = Listing {
+                seeds: &seeds,
+                almanac: &almanac,
+            }
+            .to_string();
+            assert_eq!(listing, input);
+        }
+    }
+
+    #[test]
+    fn segments() {
+        let top = u64::MAX - 10;
+        let entry = |destination_range_start, source_range_start, range_size| MapEntry {
+            destination_range_start,
+            source_range_start,
+            range_size,
+        };
+        let map = Map::new(
+            "a",
+            "b",
+            vec![
+                entry(0, top, 10),
+                entry(top, 5, 10),
+                // only what the first entry leaves
+                entry(100, top - 5, 10),
+                // overflows
+                entry(u64::MAX, 50, 2),
+            ],
+        );
+        let segments: Vec<_> = map
+            .segments()
+            .iter()
+            .map(|s| (s.source(), s.offset()))
+            .collect();
+        assert_eq!(
+            segments,
+            vec![
+                (0..5, 0),
+                (5..15, top as i128 - 5),
+                (15..top - 5, 0),
+                (top - 5..top, 100 - (top as i128 - 5)),
+                (top..u64::MAX, -(top as i128)),
+            ]
+        );
+        assert_eq!(map.remap(&(u64::MAX - 1)), 9);
+        assert_eq!(map.remap(&u64::MAX), u64::MAX);
+        assert_eq!(map.remap(&7), top + 2);
+        assert_eq!(map.remap(&(top - 3)), 102);
+        assert_eq!(map.remap(&50), 50);
+        assert_eq!(map.remap_range(12..17), vec![top + 7..top + 10, 15..17]);
+        assert_eq!(map.unmap(&2), vec![2, top + 2]);
+
+        // thousands of entries, each moving five numbers up by one
+        let entries = (0..5000).map(|i| entry(i * 10 + 1, i * 10, 5)).collect();
+        let map = Map::new("a", "b", entries);
+        assert_eq!(map.segments().len(), 10000);
+        assert_eq!(map.remap(&49_994), 49_995);
+        assert_eq!(map.remap(&49_995), 49_995);
+        let almanac = Almanac(vec![
+            map.clone(),
+            Map::new("b", "c", map.entries().to_vec()),
+        ]);
+        assert_eq!(almanac.compose().unwrap().remap(&31_233), 31_235);
+    }
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    fn remap_range() {
+        let entry = MapEntry {
+            source_range_start: 100,
+            destination_range_start: 1100,
+            range_size: 100,
+        };
+
+        assert_eq!(entry.remap_range(0..10), None);
+        assert_eq!(entry.unmap(&1150), Some(150));
+        assert_eq!(entry.unmap_range(1050..1120), Some(100..120));
+        assert_eq!(entry.unmap_range(0..1100), None);
+        assert_eq!(
+            entry.remap_range(0..150),
+            Some((1100..1150, vec![(0..100)]))
+        );
+        assert_eq!(entry.remap_range(150..160), Some((1150..1160, vec![])));
+        assert_eq!(
+            entry.remap_range(150..250),
+            Some((1150..1200, vec![200..250]))
+        );
+
+        let map = Map::new(
+            "",
+            "",
+            vec![
+                MapEntry {
+                    source_range_start: 100,
+                    destination_range_start: 1100,
+                    range_size: 100,
+                },
+                MapEntry {
+                    source_range_start: 300,
+                    destination_range_start: 100,
+                    range_size: 10,
+                },
+            ],
+        );
+        assert_eq!(map.unmap(&105), vec![305]);
+        assert_eq!(map.unmap(&1105), vec![105, 1105]);
+        assert!(map.unmap(&150).is_empty());
+        assert_eq!(
+            map.unmap_range(100..1150).into_ranges(),
+            vec![100..150, 200..1150]
+        );
+        assert_eq!(
+            map.remap_range(0..1000).iter().collect::<HashSet<_>>(),
+            [0..100, 1100..1200, 200..300, 100..110, 310..1000]
+                .iter()
+                .collect::<HashSet<_>>()
+        );
+    }
+}
@@ -0,0 +1,266 @@
+use core::ops::Range;
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, BinaryHeap};
+use std::fmt;
+
+use aoc_solution::ParseError;
+
+use crate::{parse_unchecked, Almanac, ErrorKind, Map, MapEntry};
+
+// Something off in an almanac. Only overlapping sources and overflows are
+// errors: the first would silently pick an entry by file order, the second
+// can not be computed. The rest is reported for whoever wrote the almanac.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Lint {
+    OverlappingSources,
+    OverlappingDestinations,
+    Gap(Range<u64>),
+    ZeroSized,
+    Overflow,
+    Unreachable { from: String },
+}
+
+impl Lint {
+    pub fn is_error(&self) -> bool {
+        matches!(self, Lint::OverlappingSources | Lint::Overflow)
+    }
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Lint::OverlappingSources => {
+                write!(f, "source range overlaps an earlier entry of the map")
+            }
+            Lint::OverlappingDestinations => {
+                write!(f, "destination range overlaps an earlier entry of the map")
+            }
+            Lint::Gap(gap) => write!(
+                f,
+                "{}..{} left as it is before this entry",
+                gap.start, gap.end
+            ),
+            Lint::ZeroSized => write!(f, "entry of size zero"),
+            Lint::Overflow => write!(f, "range goes past the largest number"),
+            Lint::Unreachable { from } => write!(f, "map out of reach from `{}`", from),
+        }
+    }
+}
+
+// Everything off in the almanac of `input`, in the order of the lines it is
+// found at.
+pub fn lint(input: &str) -> Result<Vec<ParseError<Lint>>, ParseError<ErrorKind>> {
+    let (_, almanac) = parse_unchecked(input)?;
+    Ok(lint_almanac(input, &almanac))
+}
+
+// Lines are found from where the names of the maps sit in `input`, so the
+// almanac must have been parsed from it.
+pub(crate) fn lint_almanac<'a>(input: &'a str, almanac: &Almanac<'a>) -> Vec<ParseError<Lint>> {
+    let lines: Vec<_> = input.lines().collect();
+    let mut lints = vec![];
+    let start = almanac.0.first().map(|m| m.source_name);
+    for map in &almanac.0 {
+        let header = ParseError::at(input, map.source_name, ()).line;
+        // entries follow their header, one per line, so their lines need
+        // not be counted again for each lint
+        lints.extend(lint_map(map).into_iter().map(|(i, kind)| ParseError {
+            line: header + i + 1,
+            column: 1,
+            text: lines[header + i].to_owned(),
+            kind,
+        }));
+
+        if let Some(start) = start {
+            if almanac.path(start, map.source_name).is_err() {
+                let kind = Lint::Unreachable {
+                    from: start.to_owned(),
+                };
+                lints.push(ParseError::at(input, map.source_name, kind));
+            }
+        }
+    }
+    lints.sort_by_key(|e| (e.line, e.column));
+    lints
+}
+
+// The lints of each entry of a map, by index.
+fn lint_map(map: &Map) -> Vec<(usize, Lint)> {
+    let mut lints = vec![];
+    let mut sound: Vec<usize> = vec![];
+    for (i, e) in map.entries().iter().enumerate() {
+        let fits = |start: u64| start.checked_add(e.range_size).is_some();
+        if !fits(e.source_range_start) || !fits(e.destination_range_start) {
+            lints.push((i, Lint::Overflow));
+        } else if e.range_size == 0 {
+            lints.push((i, Lint::ZeroSized));
+        } else {
+            sound.push(i);
+        }
+    }
+
+    let entries = map.entries();
+    for i in overlapping(entries, &mut sound, MapEntry::source_range) {
+        lints.push((i, Lint::OverlappingSources));
+    }
+    for i in overlapping(entries, &mut sound, MapEntry::destination_range) {
+        lints.push((i, Lint::OverlappingDestinations));
+    }
+
+    // a gap is only one if no earlier source, however wide, covers it
+    sound.sort_by_key(|&i| entries[i].source_range_start);
+    let mut furthest: Option<u64> = None;
+    for &i in &sound {
+        let source = entries[i].source_range();
+        if let Some(end) = furthest.filter(|&end| end < source.start) {
+            lints.push((i, Lint::Gap(end..source.start)));
+        }
+        furthest = furthest.max(Some(source.end));
+    }
+    lints
+}
+
+// Entries overlapping one earlier in the map, found in a single walk in order
+// of start. The entries walked past that have not ended yet are the ones the
+// current entry overlaps: it is flagged if one of them comes first in the map,
+// and those coming after it are flagged in its stead.
+fn overlapping(
+    entries: &[MapEntry],
+    sound: &mut [usize],
+    range: fn(&MapEntry) -> Range<u64>,
+) -> Vec<usize> {
+    sound.sort_by_key(|&i| (range(&entries[i]).start, i));
+    let mut flagged = vec![false; entries.len()];
+    // the entries still open, by end to close them and by index, along with
+    // those of them not flagged yet so that each is only flagged once
+    let mut ends = BinaryHeap::new();
+    let (mut open, mut unflagged) = (BTreeSet::new(), BTreeSet::new());
+    for &i in sound.iter() {
+        let r = range(&entries[i]);
+        while let Some(&Reverse((end, j))) = ends.peek() {
+            if end > r.start {
+                break;
+            }
+            ends.pop();
+            open.remove(&j);
+            unflagged.remove(&j);
+        }
+        flagged[i] = open.first().is_some_and(|&j| j < i);
+        while let Some(j) = unflagged.range(i..).next().copied() {
+            flagged[j] = true;
+            unflagged.remove(&j);
+        }
+        ends.push(Reverse((r.end, i)));
+        open.insert(i);
+        if !flagged[i] {
+            unflagged.insert(i);
+        }
+    }
+    (0..entries.len()).filter(|&i| flagged[i]).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_solution::Solution;
+
+    use crate::lint::*;
+    use crate::{Day05, ErrorKind};
+
+    #[test]
+    fn lints() {
+        let input = "seeds: 1 2
+
+seed-to-soil map:
+50 98 2
+52 50 48
+0 40 5
+9 60 0
+10 99 18446744073709551615
+
+water-to-light map:
+0 10 5
+3 20 5
+";
+        let lints: Vec<_> = lint(input)
+            .unwrap()
+            .into_iter()
+            .map(|e| (e.line, e.kind))
+            .collect();
+        assert_eq!(
+            lints,
+            vec![
+                (5, Lint::Gap(45..50)),
+                (7, Lint::ZeroSized),
+                (8, Lint::Overflow),
+                (
+                    10,
+                    Lint::Unreachable {
+                        from: "seed".to_owned()
+                    }
+                ),
+                (12, Lint::OverlappingDestinations),
+                (12, Lint::Gap(15..20)),
+            ]
+        );
+
+        // what lies between 10..20 and 50..60 is covered by 0..100
+        let input = "seeds: 1\n\nseed-to-soil map:\n0 0 100\n200 10 10\n300 50 10\n";
+        let gaps = lint(input)
+            .unwrap()
+            .into_iter()
+            .filter(|e| matches!(e.kind, Lint::Gap(_)))
+            .count();
+        assert_eq!(gaps, 0);
+    }
+
+    #[test]
+    fn overlaps() {
+        let lines = |entries: &str| -> Vec<_> {
+            let input = format!("seeds: 1\n\nseed-to-soil map:\n{}", entries);
+            lint(&input)
+                .unwrap()
+                .into_iter()
+                .filter(|e| e.kind == Lint::OverlappingSources)
+                .map(|e| e.line)
+                .collect()
+        };
+        // 2 and 3 overlap 1, 4 overlaps nothing but 5 reaches into it
+        assert_eq!(
+            lines("100 0 10\n200 5 2\n300 8 4\n400 20 5\n500 15 6\n"),
+            vec![5, 6, 8]
+        );
+        // 1 and 2 overlap each other inside 3
+        assert_eq!(lines("500 10 10\n600 15 10\n700 0 100\n"), vec![5, 6]);
+        // 1 and 2 lie apart inside 3 and 4, which come later
+        assert_eq!(
+            lines("500 40 5\n600 20 10\n700 0 100\n800 10 40\n"),
+            vec![6, 7]
+        );
+
+        // twenty thousand entries, each odd one sending numbers into the
+        // destination of the next
+        let mut input = "seeds: 1\n\nseed-to-soil map:\n".to_owned();
+        for i in 0..20_000u64 {
+            let destination = if i % 2 == 0 { i * 10 } else { i * 10 + 5 };
+            input += &format!("{} {} 10\n", destination, i * 10);
+        }
+        let lints = lint(&input).unwrap();
+        let count = |kind: Lint| lints.iter().filter(|e| e.kind == kind).count();
+        assert_eq!(count(Lint::OverlappingSources), 0);
+        assert_eq!(count(Lint::OverlappingDestinations), 9_999);
+    }
+
+    #[test]
+    fn errors() {
+        let e = Day05::parse("seeds: 1\n\nseed-to-soil map:\n0 10 5\n20 12 5\n").unwrap_err();
+        assert_eq!(
+            (e.line, e.column, e.text.as_str(), e.kind),
+            (
+                5,
+                1,
+                "20 12 5",
+                ErrorKind::Invalid(Lint::OverlappingSources)
+            )
+        );
+    }
+}
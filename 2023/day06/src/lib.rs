@@ -0,0 +1,302 @@
+// t :       (ms)      time the button is pressed
+// t_max :   (ms)      maximum allowed time for the race
+// v :       (mm/ms)   speed that the boat has when the button is released
+// d :       (mm)      distance that the boat eventually makes
+// k :       (mms/ms²) speed gained per millisecond of pressing the button
+
+// d = (k * t) * (t_max - t)
+//
+//     |_____|   |_________|
+//        v         delta_t
+//
+// the distance is fully characterized by the following second degree polynomial:
+//    d = -k * t²  + k*t_max * t
+//
+// Its shape is an upside down parabolla:
+//
+//    d ^
+//      |
+//      |            xx
+//      |          x    x
+//      |        x        x
+//      |       x          x
+//      |      x            x
+//      |     x              x
+//      |    x                x
+//      +------------------------------> t
+//
+//
+// we need to find all speeds that reach beyond the current record d0
+//
+//
+//    d ^
+//      |
+//      |            xx
+//      |          x    x
+//      |        x        x
+//   d0 +-------x----------x------------
+//      |      x|          |x
+//      |     x |          | x
+//      |    x  |          |  x
+//      +-------+----------+-----------> t
+//              t1         t2
+//
+// t1 and t2 are the roots of the following second degree polynomial:
+//
+//          -k * t² + k*t_max * t - d0
+//
+// or        a * t² + b * t + c
+//
+// with      a = -k
+//           b = k * t_max
+//           c = -d0
+//
+//
+// the general solution of this problem is the following:
+//
+// delta = b² - 4*ac
+//
+// t1 = (-b - sqrt(delta)) / (2*a)
+// t2 = (-b + sqrt(delta)) / (2*a)
+//
+// if delta is negative, there are no real solutions, only imaginary ones
+// if delta is equal to zero, the two roots t1 and t2 are equal
+//
+// floats lose track of whether a root is an integer once the numbers get
+// large, so all of it is done on integers: delta fits a u128, its integer
+// square root gives t1 to within one, and the first winning press is found
+// by checking the integers next to it. The parabolla being symmetric around
+// t_max / 2, the last winning press is t_max - t1.
+
+use std::fmt;
+use std::ops::Range;
+
+use aoc_solution::{ParseError, Solution};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 6;
+
+    type Model<'a> = RaceSheet;
+    type Error = ParseError<ErrorKind>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<RaceSheet, Self::Error> {
+        let mut lines = input.lines();
+        let mut next_row = |label| {
+            let line = lines.next().ok_or(ParseError::at(
+                input,
+                &input[input.len()..],
+                ErrorKind::MissingLine(label),
+            ))?;
+            parse_row(input, line, label)
+        };
+
+        let (times, time) = next_row("Time:")?;
+        let (distances, distance) = next_row("Distance:")?;
+        if times.len() != distances.len() {
+            let line = input.lines().nth(1).unwrap();
+            return Err(ParseError::at(input, line, ErrorKind::ColumnMismatch));
+        }
+
+        Ok(RaceSheet {
+            races: times
+                .into_iter()
+                .zip(distances)
+                .map(|(t_max, current_record)| BoatRace {
+                    t_max,
+                    current_record,
+                })
+                .collect(),
+            long_race: BoatRace {
+                t_max: time,
+                current_record: distance,
+            },
+        })
+    }
+
+    fn part1(sheet: &RaceSheet) -> usize {
+        sheet
+            .races
+            .iter()
+            .map(|r| r.winning_moves().len())
+            .product::<usize>()
+    }
+
+    fn part2(sheet: &RaceSheet) -> usize {
+        sheet.long_race.winning_moves().len()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ErrorKind {
+    MissingLine(&'static str),
+    Unexpected(&'static str),
+    InvalidNumber,
+    ColumnMismatch,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::MissingLine(label) => write!(f, "missing `{}` line", label),
+            ErrorKind::Unexpected(label) => write!(f, "expected `{}`", label),
+            ErrorKind::InvalidNumber => write!(f, "invalid number"),
+            ErrorKind::ColumnMismatch => write!(f, "not as many distances as times"),
+        }
+    }
+}
+
+// Parses the numbers of a row, along with the one number they make once read
+// as a single race.
+fn parse_row(
+    input: &str,
+    line: &str,
+    label: &'static str,
+) -> Result<(Vec<usize>, usize), ParseError<ErrorKind>> {
+    let numbers = line.strip_prefix(label).ok_or(ParseError::at(
+        input,
+        line,
+        ErrorKind::Unexpected(label),
+    ))?;
+
+    let races = aoc_input::numbers(numbers)
+        .map_err(|e| ParseError::at(input, e.at, ErrorKind::InvalidNumber))?;
+    let long_race = numbers
+        .split_ascii_whitespace()
+        .collect::<String>()
+        .parse()
+        .map_err(|_| ParseError::at(input, numbers.trim_start(), ErrorKind::InvalidNumber))?;
+    Ok((races, long_race))
+}
+
+pub struct RaceSheet {
+    pub races: Vec<BoatRace>,
+    // the kerning on the sheet is bad: all columns are actually a single race
+    pub long_race: BoatRace,
+}
+
+pub const K: u128 = 1;
+#[derive(Debug)]
+pub struct BoatRace {
+    pub t_max: usize,
+    pub current_record: usize,
+}
+
+impl BoatRace {
+    pub fn distance(&self, t: usize) -> u128 {
+        K * t as u128 * (self.t_max - t) as u128
+    }
+
+    fn wins(&self, t: usize) -> bool {
+        t <= self.t_max && self.distance(t) > self.current_record as u128
+    }
+
+    // Empty when the record can not be beaten.
+    pub fn winning_moves(&self) -> Range<usize> {
+        let (t_max, d0) = (self.t_max as u128, self.current_record as u128);
+        // b² - 4ac, with b = k * t_max, a = -k and c = -d0
+        let Some(delta) = (K * t_max * K * t_max).checked_sub(4 * K * d0) else {
+            return 0..0;
+        };
+        let t1 = (K * t_max - delta.isqrt()) / (2 * K);
+
+        let mut t1 = t1 as usize;
+        while t1 > 0 && self.wins(t1 - 1) {
+            t1 -= 1;
+        }
+        while t1 <= self.t_max / 2 && !self.wins(t1) {
+            t1 += 1;
+        }
+        if !self.wins(t1) {
+            return 0..0;
+        }
+        t1..self.t_max - t1 + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::*;
+
+    #[test]
+    fn examples() {
+        aoc_solution::check_examples::<Day06>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn winning_moves() {
+        let races = [
+            BoatRace {
+                t_max: 7,
+                current_record: 9,
+            },
+            BoatRace {
+                t_max: 15,
+                current_record: 40,
+            },
+            BoatRace {
+                t_max: 30,
+                current_record: 200,
+            },
+        ];
+
+        assert_eq!(
+            races.iter().map(|r| r.winning_moves()).collect::<Vec<_>>(),
+            vec![2..6, 4..12, 11..20]
+        );
+
+        // only presses m - 1 to m + 1 get further than m² - 4
+        let m = 3_000_000_000;
+        let race = |t_max, current_record| BoatRace {
+            t_max,
+            current_record,
+        };
+        assert_eq!(race(2 * m, m * m - 4).winning_moves(), m - 1..m + 2);
+        assert_eq!(race(2 * m, m * m - 1).winning_moves(), m..m + 1);
+        assert!(race(2 * m, m * m).winning_moves().is_empty());
+        assert!(race(2 * m + 1, m * m + m).winning_moves().is_empty());
+        assert_eq!(race(usize::MAX, 0).winning_moves(), 1..usize::MAX);
+        assert!(race(0, 0).winning_moves().is_empty());
+        assert!(race(5, 100).winning_moves().is_empty());
+    }
+
+    #[test]
+    fn unwinnable_races() {
+        let sheet = Day06::parse("Time: 5\nDistance: 100\n").unwrap();
+        assert_eq!((Day06::part1(&sheet), Day06::part2(&sheet)), (0, 0));
+
+        let sheet = Day06::parse("Time: 7 5\nDistance: 9 100\n").unwrap();
+        assert_eq!(Day06::part1(&sheet), 0);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let sheet = Day06::parse("Time:      7  15   30\nDistance:  9  40  200\n").unwrap();
+        assert_eq!(sheet.races.len(), 3);
+        assert_eq!(
+            (sheet.long_race.t_max, sheet.long_race.current_record),
+            (71530, 940200)
+        );
+
+        let e = Day06::parse("Time:      7  15   30\n").err().unwrap();
+        assert_eq!((e.line, e.kind), (2, ErrorKind::MissingLine("Distance:")));
+
+        let e = Day06::parse("Time:      7  1x   30\nDistance:  9  40  200")
+            .err()
+            .unwrap();
+        assert_eq!(
+            (e.line, e.column, e.kind),
+            (1, 15, ErrorKind::InvalidNumber)
+        );
+
+        let e = Day06::parse("Time:      7  15\nDistance:  9  40  200")
+            .err()
+            .unwrap();
+        assert_eq!((e.line, e.kind), (2, ErrorKind::ColumnMismatch));
+    }
+}
@@ -1,225 +1,7 @@
-// t :       (ms)      time the button is pressed
-// t_max :   (ms)      maximum allowed time for the race
-// v :       (mm/ms)   speed that the boat has when the button is released
-// d :       (mm)      distance that the boat eventually makes
-// k :       (mms/ms²) speed gained per millisecond of pressing the button
+use std::process::ExitCode;
 
-// d = (k * t) * (t_max - t)
-//
-//     |_____|   |_________|
-//        v         delta_t
-//
-// the distance is fully characterized by the following second degree polynomial:
-//    d = -k * t²  + k*t_max * t
-//
-// Its shape is an upside down parabolla:
-//
-//    d ^
-//      |
-//      |            xx
-//      |          x    x
-//      |        x        x
-//      |       x          x
-//      |      x            x
-//      |     x              x
-//      |    x                x
-//      +------------------------------> t
-//
-//
-// we need to find all speeds that reach beyond the current record d0
-//
-//
-//    d ^
-//      |
-//      |            xx
-//      |          x    x
-//      |        x        x
-//   d0 +-------x----------x------------
-//      |      x|          |x
-//      |     x |          | x
-//      |    x  |          |  x
-//      +-------+----------+-----------> t
-//              t1         t2
-//
-// t1 and t2 are the roots of the following second degree polynomial:
-//
-//          -k * t² + k*t_max * t - d0
-//
-// or        a * t² + b * t + c
-//
-// with      a = -k
-//           b = k * t_max
-//           c = -d0
-//
-//
-// the general solution of this problem is the following:
-//
-// delta = b² - 4*ac
-//
-// t1 = (-b - sqrt(delta)) / (2*a)
-// t2 = (-b + sqrt(delta)) / (2*a)
-//
-// if delta is negative, there are no real solutions, only imaginary ones
-// if delta is equal to zero, the two roots t1 and t2 are equal
+use day06::Day06;
 
-use std::fs::File;
-use std::io::{self, BufRead};
-use std::ops::Range;
-use std::path::Path;
-
-fn main() {
-    let mut lines = read_lines("./input.txt").unwrap();
-
-    let times = lines.next().unwrap().unwrap();
-    let distances = lines.next().unwrap().unwrap();
-
-    let times = times
-        .chars()
-        .skip_while(|c| !c.is_ascii_digit())
-        .collect::<String>();
-    let distances = distances
-        .chars()
-        .skip_while(|c| !c.is_ascii_digit())
-        .collect::<String>();
-
-    let times: Vec<_> = times.split_ascii_whitespace().collect();
-    let distances: Vec<_> = distances.split_ascii_whitespace().collect();
-
-    let pt1_races: Vec<_> = times
-        .iter()
-        .zip(distances.iter())
-        .map(|(time, dist)| BoatRace {
-            current_record: dist.parse().unwrap(),
-            t_max: time.parse().unwrap(),
-        })
-        .collect();
-    println!(
-        "Pt 1 - product of all possible moves: {}",
-        pt1_races
-            .iter()
-            .filter_map(|r| r.winning_moves().map(|r| r.len()))
-            .product::<usize>(),
-    );
-
-    let pt2_race = BoatRace {
-        current_record: distances
-            .iter()
-            .flat_map(|s| s.chars())
-            .collect::<String>()
-            .parse::<usize>()
-            .unwrap(),
-        t_max: times
-            .iter()
-            .flat_map(|s| s.chars())
-            .collect::<String>()
-            .parse::<usize>()
-            .unwrap(),
-    };
-
-    println!(
-        "Pt 2 - ways to win long race: {}",
-        pt2_race.winning_moves().unwrap().len()
-    );
-}
-
-fn read_lines<P>(filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
-where
-    P: AsRef<Path>,
-{
-    let file = File::open(filename)?;
-    Ok(io::BufReader::new(file).lines())
-}
-
-struct SecondDegreePolynomial {
-    a: i64,
-    b: i64,
-    c: i64,
-}
-
-impl SecondDegreePolynomial {
-    fn real_roots(&self) -> Option<(f64, f64)> {
-        let delta = self.b * self.b - (4 * self.a * self.c);
-
-        if delta < 0 {
-            return None;
-        }
-
-        let (a, b, sq_delta) = (self.a as f64, self.b as f64, (delta as f64).sqrt());
-
-        Some(((-b + sq_delta) / (2.0 * a), (-b - sq_delta) / (2.0 * a)))
-    }
-}
-
-const K: i64 = 1;
-#[derive(Debug)]
-struct BoatRace {
-    t_max: usize,
-    current_record: usize,
-}
-
-impl BoatRace {
-    fn winning_moves(&self) -> Option<Range<usize>> {
-        let pol = SecondDegreePolynomial {
-            a: -K,
-            b: K * self.t_max as i64,
-            c: -(self.current_record as i64),
-        };
-        if let Some((t1, t2)) = pol.real_roots() {
-            let is_integer = |f: f64| f.fract() < (10.0 * f64::EPSILON);
-            let t1 = if is_integer(t1) {
-                t1 as usize + 1
-            } else {
-                t1.ceil() as usize
-            };
-            let t2 = if is_integer(t2) {
-                t2 as usize
-            } else {
-                t2.floor() as usize + 1
-            };
-
-            if t1 > t2 {
-                None
-            } else {
-                Some(t1..t2)
-            }
-        } else {
-            None
-        }
-    }
-}
-
-#[cfg(test)]
-mod tests {
-
-    use crate::*;
-    #[test]
-    fn winning_moves() {
-        let races = vec![
-            BoatRace {
-                t_max: 7,
-                current_record: 9,
-            },
-            BoatRace {
-                t_max: 15,
-                current_record: 40,
-            },
-            BoatRace {
-                t_max: 30,
-                current_record: 200,
-            },
-        ];
-
-        assert_eq!(
-            races.iter().map(|r| r.winning_moves()).collect::<Vec<_>>(),
-            vec![Some(2..6), Some(4..12), Some(11..20)]
-        );
-
-        assert_eq!(
-            races
-                .iter()
-                .filter_map(|r| r.winning_moves().map(|r| r.len()))
-                .product::<usize>(),
-            288
-        );
-    }
+fn main() -> ExitCode {
+    aoc_solution::main::<Day06>()
 }
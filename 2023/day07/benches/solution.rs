@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use day07::Day07;
+
+fn main() -> ExitCode {
+    aoc_solution::bench::run::<Day07>(env!("CARGO_MANIFEST_DIR"))
+}
@@ -0,0 +1,174 @@
+use nom::bytes::complete::tag;
+use nom::character::complete::{alphanumeric1, multispace0};
+use nom::combinator::all_consuming;
+use nom::sequence::{delimited, separated_pair, tuple};
+use num::integer::lcm;
+use std::collections::HashMap;
+use std::fmt;
+
+use aoc_solution::{ErrorAt, ParseError, Solution};
+
+type IResult<I, O> = nom::IResult<I, O, ErrorAt<I, ErrorKind>>;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 8;
+
+    type Model<'a> = Network;
+    type Error = ParseError<ErrorKind>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Network, Self::Error> {
+        let mut lines = input.lines();
+
+        let instructions = lines.next().unwrap_or_default();
+        if instructions.is_empty() {
+            return Err(ParseError::at(
+                input,
+                instructions,
+                ErrorKind::NoInstructions,
+            ));
+        }
+        if let Some(i) = instructions.find(|c| c != 'L' && c != 'R') {
+            let at = &instructions[i..i + 1];
+            return Err(ParseError::at(input, at, ErrorKind::UnknownDirection));
+        }
+        lines.next();
+
+        let nodes = lines
+            .map(|line| {
+                all_consuming(parse_node)(line)
+                    .map(|(_, node)| node)
+                    .map_err(|e| ParseError::from_nom(input, e))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let names: HashMap<_, _> = nodes.iter().map(|(n, lr)| (*n, lr)).collect();
+        if let Some(unknown) = nodes
+            .iter()
+            .flat_map(|(_, (l, r))| [l, r])
+            .find(|n| !names.contains_key(*n))
+        {
+            return Err(ParseError::at(input, unknown, ErrorKind::UnknownNode));
+        }
+
+        let graph = nodes
+            .into_iter()
+            .map(|(n, (l, r))| (n.to_owned(), (l.to_owned(), r.to_owned())))
+            .collect();
+        Ok(Network {
+            instructions: instructions.to_owned(),
+            graph,
+        })
+    }
+
+    fn part1(network: &Network) -> usize {
+        network.moves("AAA", |n| n == "ZZZ")
+    }
+
+    fn part2(network: &Network) -> usize {
+        let ghost_start_nodes: Vec<_> = network.graph.keys().filter(|k| k.ends_with('A')).collect();
+        let moves_for_each_node = ghost_start_nodes
+            .into_iter()
+            .map(|n| network.moves(n, |n| n.ends_with('Z')))
+            .collect::<Vec<_>>();
+
+        moves_for_each_node.into_iter().fold(1, lcm)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ErrorKind {
+    NoInstructions,
+    UnknownDirection,
+    UnknownNode,
+    Unexpected,
+}
+
+impl From<nom::error::ErrorKind> for ErrorKind {
+    fn from(_: nom::error::ErrorKind) -> Self {
+        ErrorKind::Unexpected
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::NoInstructions => write!(f, "missing instructions"),
+            ErrorKind::UnknownDirection => write!(f, "unknown direction"),
+            ErrorKind::UnknownNode => write!(f, "unknown node"),
+            ErrorKind::Unexpected => write!(f, "unexpected input"),
+        }
+    }
+}
+
+pub struct Network {
+    pub instructions: String,
+    pub graph: HashMap<String, (String, String)>,
+}
+
+impl Network {
+    pub fn moves(&self, start: &str, is_end: impl Fn(&str) -> bool) -> usize {
+        let mut current_node = start;
+        let mut moves = 0;
+        for direction in self.instructions.chars().cycle() {
+            if is_end(current_node) {
+                break;
+            }
+            moves += 1;
+            match direction {
+                'L' => current_node = &self.graph[current_node].0,
+                'R' => current_node = &self.graph[current_node].1,
+                _ => unreachable!("directions are checked when parsing"),
+            }
+        }
+        moves
+    }
+}
+
+fn parse_node(input: &str) -> IResult<&str, (&str, (&str, &str))> {
+    separated_pair(
+        alphanumeric1,
+        tuple((multispace0, tag("="), multispace0)),
+        delimited(
+            tag("("),
+            separated_pair(alphanumeric1, tag(", "), alphanumeric1),
+            tag(")"),
+        ),
+    )(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn examples() {
+        aoc_solution::check_examples::<Day08>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let network = "LR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)";
+        assert_eq!(Day08::part1(&Day08::parse(network).unwrap()), 2);
+
+        let e = Day08::parse("LRX\n\nAAA = (AAA, AAA)").err().unwrap();
+        assert_eq!(
+            (e.line, e.column, e.kind),
+            (1, 3, ErrorKind::UnknownDirection)
+        );
+
+        let e = Day08::parse("L\n\nAAA = (BBB, CCC)\nBBB = (AAA, AAA)")
+            .err()
+            .unwrap();
+        assert_eq!(
+            (e.line, e.column, e.text.as_str(), e.kind),
+            (3, 13, "CCC", ErrorKind::UnknownNode)
+        );
+
+        let e = Day08::parse("L\n\nAAA = (AAA AAA)").err().unwrap();
+        assert_eq!((e.line, e.column, e.kind), (3, 11, ErrorKind::Unexpected));
+    }
+}
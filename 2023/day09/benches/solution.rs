@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use day09::Day09;
+
+fn main() -> ExitCode {
+    aoc_solution::bench::run::<Day09>(env!("CARGO_MANIFEST_DIR"))
+}
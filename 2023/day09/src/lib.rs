@@ -0,0 +1,104 @@
+use std::fmt;
+
+use aoc_solution::{ParseError, Solution};
+use itertools::Itertools;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 9;
+
+    type Model<'a> = Vec<Vec<i64>>;
+    type Error = ParseError<ErrorKind>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<Vec<i64>>, Self::Error> {
+        input
+            .lines()
+            .map(|l| {
+                aoc_input::numbers(l)
+                    .map_err(|e| ParseError::at(input, e.at, ErrorKind::InvalidNumber))
+            })
+            .collect()
+    }
+
+    fn part1(histories: &Vec<Vec<i64>>) -> i64 {
+        histories.iter().map(|h| extrapolate(h.clone())).sum()
+    }
+
+    fn part2(histories: &Vec<Vec<i64>>) -> i64 {
+        histories
+            .iter()
+            .map(|h| extrapolate(h.iter().rev().copied().collect()))
+            .sum()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ErrorKind {
+    InvalidNumber,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::InvalidNumber => write!(f, "invalid number"),
+        }
+    }
+}
+
+pub fn adjacent_difference(input: Vec<i64>) -> Vec<i64> {
+    input
+        .into_iter()
+        .tuple_windows::<(_, _)>()
+        .map(|(a, b)| b - a)
+        .collect()
+}
+
+pub fn extrapolate(input: Vec<i64>) -> i64 {
+    let mut input = input;
+    let mut tails = vec![];
+    while let Some(tail) = input.last().copied() {
+        tails.push(tail);
+        input = adjacent_difference(input);
+    }
+    tails.into_iter().sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn examples() {
+        aoc_solution::check_examples::<Day09>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn adj_diff() {
+        assert_eq!(adjacent_difference(vec![1, 2, 3]), vec![1, 1]);
+        assert_eq!(adjacent_difference(vec![1]), Vec::<i64>::new());
+    }
+
+    #[test]
+    fn test_extrapolate() {
+        assert_eq!(extrapolate(vec![1, 2, 3]), 4);
+
+        assert_eq!(extrapolate(vec![0, 3, 6, 9, 12, 15]), 18);
+        assert_eq!(extrapolate(vec![1, 3, 6, 10, 15, 21]), 28);
+        assert_eq!(extrapolate(vec![10, 13, 16, 21, 30, 45]), 68);
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(Day09::parse("0 -3 6\n1"), Ok(vec![vec![0, -3, 6], vec![1]]));
+
+        let e = Day09::parse("0 3 6\n1 3 six").unwrap_err();
+        assert_eq!(
+            (e.line, e.column, e.text.as_str(), e.kind),
+            (2, 5, "six", ErrorKind::InvalidNumber)
+        );
+    }
+}
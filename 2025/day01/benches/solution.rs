@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use day01::Day01;
+
+fn main() -> ExitCode {
+    aoc_solution::bench::run::<Day01>(env!("CARGO_MANIFEST_DIR"))
+}
@@ -0,0 +1,153 @@
+use std::fmt;
+
+use aoc_solution::{ErrorAt, ParseError, Solution};
+
+pub const DIAL_INIT: u8 = 50;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 1;
+
+    type Model<'a> = Vec<i16>;
+    type Error = ParseError<ErrorKind>;
+    type Answer1 = u16;
+    type Answer2 = u16;
+
+    fn parse(input: &str) -> Result<Vec<i16>, Self::Error> {
+        input
+            .lines()
+            .map(|l| parse_line(l).map_err(|e| ParseError::locate(input, e)))
+            .collect()
+    }
+
+    fn part1(commands: &Vec<i16>) -> u16 {
+        let mut dial = DIAL_INIT;
+        let mut zeros_pt1 = 0;
+        for com in commands {
+            dial = rot(dial, *com).0;
+            if dial == 0 {
+                zeros_pt1 += 1;
+            }
+        }
+        zeros_pt1
+    }
+
+    fn part2(commands: &Vec<i16>) -> u16 {
+        let mut dial = DIAL_INIT;
+        let mut zero_crossings_pt2: u16 = 0;
+        for com in commands {
+            let (new_dial, zc) = rot(dial, *com);
+            dial = new_dial;
+            zero_crossings_pt2 += zc as u16;
+        }
+        zero_crossings_pt2
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ErrorKind {
+    UnknownDirection,
+    InvalidNumber,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::UnknownDirection => write!(f, "unknown direction"),
+            ErrorKind::InvalidNumber => write!(f, "invalid number"),
+        }
+    }
+}
+
+fn parse_line(line: &str) -> Result<i16, ErrorAt<&str, ErrorKind>> {
+    let (mult, num) = if let Some(num) = line.strip_prefix('L') {
+        (-1, num)
+    } else if let Some(num) = line.strip_prefix('R') {
+        (1, num)
+    } else {
+        return Err(ErrorAt::new(line, ErrorKind::UnknownDirection));
+    };
+
+    let num = num
+        .parse::<u16>()
+        .ok()
+        .and_then(|n| i16::try_from(n).ok())
+        .ok_or(ErrorAt::new(num, ErrorKind::InvalidNumber))?;
+    Ok(mult * num)
+}
+
+pub fn rot(dial: u8, l_r: i16) -> (u8, u8) {
+    if l_r == 0 {
+        return (dial, 0);
+    }
+    let mut new_dial = dial as i16;
+    let mut zero_crossings: u8 = 0;
+    zero_crossings += (l_r.abs() / 100) as u8;
+
+    new_dial += l_r % 100;
+
+    if new_dial < 0 {
+        new_dial += 100;
+        if dial != 0 {
+            zero_crossings += 1;
+        }
+    } else if new_dial > 99 {
+        new_dial -= 100;
+        if dial != 0 {
+            zero_crossings += 1;
+        }
+    } else if new_dial == 0 {
+        zero_crossings += 1;
+    }
+    (new_dial.try_into().unwrap(), zero_crossings)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn examples() {
+        aoc_solution::check_examples::<Day01>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn rot_wrap() {
+        assert_eq!(rot(1, -2).0, 99);
+        assert_eq!(rot(99, 2).0, 1);
+        assert_eq!(rot(50, -100).0, 50);
+        assert_eq!(rot(50, 100).0, 50);
+    }
+    #[test]
+    fn rot_zero_crossings() {
+        assert_eq!(rot(1, -2).1, 1);
+        assert_eq!(rot(99, 2).1, 1);
+        assert_eq!(rot(50, -100).1, 1);
+        assert_eq!(rot(50, 100).1, 1);
+        assert_eq!(rot(50, 1000).1, 10);
+        assert_eq!(rot(50, -1000).1, 10);
+        assert_eq!(rot(50, 50).1, 1);
+    }
+
+    #[test]
+    fn parse_ok() {
+        assert_eq!(parse_line("R99"), Ok(99));
+        assert_eq!(parse_line("L99"), Ok(-99));
+        assert_eq!(parse_line("R999"), Ok(999));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let e = Day01::parse("R99\nU12").unwrap_err();
+        assert_eq!((e.line, e.column, e.kind), (2, 1, ErrorKind::UnknownDirection));
+
+        let e = Day01::parse("R99\nL-1").unwrap_err();
+        assert_eq!(
+            (e.line, e.column, e.text.as_str(), e.kind),
+            (2, 2, "-1", ErrorKind::InvalidNumber)
+        );
+        assert!(Day01::parse("R40000").is_err());
+    }
+}
@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use day02::Day02;
+
+fn main() -> ExitCode {
+    aoc_solution::bench::run::<Day02>(env!("CARGO_MANIFEST_DIR"))
+}
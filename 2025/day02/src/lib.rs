@@ -0,0 +1,161 @@
+use std::fmt;
+use std::ops::RangeInclusive;
+
+use aoc_solution::{ParseError, Solution};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 2;
+
+    type Model<'a> = Vec<RangeInclusive<u64>>;
+    type Error = ParseError<ErrorKind>;
+    type Answer1 = u128;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> Result<Vec<RangeInclusive<u64>>, Self::Error> {
+        let number = |n: &str| {
+            n.parse::<u64>()
+                .map_err(|_| ParseError::at(input, n, ErrorKind::InvalidNumber))
+        };
+
+        let mut ranges = vec![];
+        for range in input.trim_end().split(',') {
+            let (start, end) = range
+                .split_once('-')
+                .ok_or(ParseError::at(input, range, ErrorKind::NotARange))?;
+
+            ranges.push(number(start)?..=number(end)?);
+        }
+        Ok(ranges)
+    }
+
+    fn part1(ranges: &Vec<RangeInclusive<u64>>) -> u128 {
+        sum_of_invalid_ids(ranges, is_invalid_pt1)
+    }
+
+    fn part2(ranges: &Vec<RangeInclusive<u64>>) -> u128 {
+        sum_of_invalid_ids(ranges, is_invalid_pt2)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ErrorKind {
+    NotARange,
+    InvalidNumber,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::NotARange => write!(f, "expected a `start-end` range"),
+            ErrorKind::InvalidNumber => write!(f, "invalid number"),
+        }
+    }
+}
+
+pub fn sum_of_invalid_ids(ranges: &[RangeInclusive<u64>], is_invalid: fn(u64) -> bool) -> u128 {
+    let mut acc: u128 = 0;
+    for range in ranges {
+        for i in range.clone() {
+            if is_invalid(i) {
+                acc += i as u128;
+            }
+        }
+    }
+    acc
+}
+
+pub fn base_10_len(n: u64) -> u8 {
+    match n {
+        0 => 1,
+        _ => n.ilog10() as u8 + 1,
+    }
+}
+
+// fn bisect(n: u64) -> Option<(u64, u64)> {
+//     let len = base_10_len(n);
+//     if (len % 2) != 0 {
+//         return None;
+//     }
+//     let power_of_ten = 10u64.pow(len as u32 / 2);
+
+//     let top_n = n / power_of_ten;
+//     let bottom_n = n - (power_of_ten * top_n);
+
+//     Some((top_n, bottom_n))
+// }
+
+pub fn is_invalid_pt1(n: u64) -> bool {
+    is_invalid_k(n,2)
+    // match bisect(n) {
+    //     Some((a, b)) => a == b,
+    //     None => false,
+    // }
+}
+
+pub fn is_invalid_pt2(n: u64) -> bool {
+    let len = base_10_len(n);
+    for order in 2..=len {
+        if is_invalid_k(n, order) {
+            return true;
+        }
+    }
+    false
+}
+
+pub fn is_invalid_k(n: u64, order: u8) -> bool {
+    let len = base_10_len(n);
+
+    if !len.is_multiple_of(order) {
+        return false;
+    }
+    let power_of_ten = 10u64.pow(len as u32 / order as u32);
+
+    let motif = n - (n / power_of_ten) * power_of_ten;
+    let mut rest = n;
+    for _i in 0..order {
+        let bottom = rest - (rest / power_of_ten) * power_of_ten;
+        rest /= power_of_ten;
+        if bottom != motif {
+            return false;
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn examples() {
+        aoc_solution::check_examples::<Day02>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn base_10_len_test() {
+        for i in 0..1024 {
+            assert_eq!(base_10_len(i), i.to_string().len() as u8);
+        }
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(Day02::parse("11-22,95-115\n"), Ok(vec![11..=22, 95..=115]));
+
+        let e = Day02::parse("11-22,95").unwrap_err();
+        assert_eq!((e.column, e.text.as_str(), e.kind), (7, "95", ErrorKind::NotARange));
+
+        let e = Day02::parse("11-22,95-1x5").unwrap_err();
+        assert_eq!((e.column, e.text.as_str(), e.kind), (10, "1x5", ErrorKind::InvalidNumber));
+    }
+
+    // #[test]
+    // fn bisect_test() {
+    //     assert_eq!(bisect(0), None);
+    //     assert_eq!(bisect(10), Some((1, 0)));
+    //     assert_eq!(bisect(1234), Some((12, 34)));
+    // }
+}
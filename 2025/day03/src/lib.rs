@@ -0,0 +1,159 @@
+use std::fmt;
+
+use aoc_input::Malformed;
+use aoc_solution::{ParseError, Solution};
+
+pub const BATTERIES_PT2: u8 = 12;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 3;
+
+    type Model<'a> = Vec<&'a str>;
+    type Error = ParseError<ErrorKind>;
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<&str>, Self::Error> {
+        let lines = aoc_input::lines(input).map_err(|e| ParseError::locate(input, e))?;
+        let first = lines[0];
+
+        for line in &lines {
+            if let Some(i) = line.find(|c: char| !c.is_ascii_digit()) {
+                return Err(ParseError::at(input, &line[i..], ErrorKind::InvalidDigit));
+            }
+            if line.len() < BATTERIES_PT2 as usize {
+                return Err(ParseError::at(input, line, ErrorKind::TooShort));
+            }
+            if line.len() != first.len() {
+                return Err(ParseError::at(input, line, ErrorKind::UnevenBanks));
+            }
+        }
+        Ok(lines)
+    }
+
+    fn part1(lines: &Vec<&str>) -> u32 {
+        let n = lines.first().unwrap().len();
+
+        let mut pt1: u32 = 0;
+        for line in lines {
+            let sline = &line[..n - 1];
+
+            let first = sline.chars().max().unwrap();
+            let first_index = sline.find(first).unwrap();
+
+            let eline = &line[first_index + 1..];
+            let second = eline.chars().max().unwrap();
+
+            let num = 10 * (first as u8 - b'0') + (second as u8 - b'0');
+
+            pt1 += num as u32;
+        }
+        pt1
+    }
+
+    fn part2(lines: &Vec<&str>) -> u64 {
+        lines.iter().map(|line| full_chop(line, BATTERIES_PT2)).sum()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ErrorKind {
+    Empty,
+    InvalidDigit,
+    TooShort,
+    UnevenBanks,
+}
+
+impl From<Malformed> for ErrorKind {
+    fn from(e: Malformed) -> Self {
+        match e {
+            Malformed::Empty => ErrorKind::Empty,
+            Malformed::UnevenRows => ErrorKind::UnevenBanks,
+        }
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::Empty => write!(f, "no battery bank"),
+            ErrorKind::InvalidDigit => write!(f, "invalid joltage digit"),
+            ErrorKind::TooShort => write!(f, "not enough batteries in bank"),
+            ErrorKind::UnevenBanks => write!(f, "banks of different sizes"),
+        }
+    }
+}
+
+pub fn chop(line: &str, i: u8) -> (u8, &str) {
+    let n = line.len();
+    let slice = &line[..n - (i - 1) as usize];
+    let first = slice.chars().max().unwrap();
+    let first_index = slice.find(first).unwrap();
+
+    (first as u8 - b'0', &line[first_index + 1..n])
+}
+
+pub fn full_chop(line: &str, order: u8) -> u64 {
+    let mut acc: u64 = 0;
+    let mut remaining = line;
+    for i in 0..order {
+        let (n, rest) = chop(remaining, order - i);
+        acc += n as u64 * 10u64.pow((order - i - 1) as u32);
+        remaining = rest;
+    }
+    acc
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn examples() {
+        aoc_solution::check_examples::<Day03>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn chop_test() {
+        assert_eq!(chop("987654321111111", 12), (9, "87654321111111"));
+        assert_eq!(chop("87654321111111", 11), (8, "7654321111111"));
+    }
+    #[test]
+    fn full_chop_test() {
+        assert_eq!(full_chop("9", 1), 9);
+        assert_eq!(full_chop("97", 2), 97);
+        assert_eq!(full_chop("979", 2), 99);
+    
+        assert_eq!(full_chop("987654321111111", 12), 987654321111);
+        assert_eq!(full_chop("234234234234278", 12), 434234234278);
+    }
+
+    // pt1 reimplemented in terms of the more generic solution written for pt2
+    #[test]
+    fn pt1_alt() {
+        let lines = vec![
+            "987654321111111",
+            "811111111111119",
+            "234234234234278",
+            "818181911112111",
+        ];
+        let pt1_alt: u32 = lines.iter().map(|l| full_chop(l, 2) as u32).sum();
+        assert_eq!(Day03::part1(&lines), 357);
+        assert_eq!(pt1_alt, 357);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let e = Day03::parse("987654321111111\n8111111x1111119").unwrap_err();
+        assert_eq!((e.line, e.column, e.kind), (2, 8, ErrorKind::InvalidDigit));
+
+        let e = Day03::parse("987654321111111\n81111119").unwrap_err();
+        assert_eq!((e.line, e.kind), (2, ErrorKind::TooShort));
+
+        let e = Day03::parse("").unwrap_err();
+        assert_eq!(e.kind, ErrorKind::Empty);
+    }
+}
@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use day04::Day04;
+
+fn main() -> ExitCode {
+    aoc_solution::bench::run::<Day04>(env!("CARGO_MANIFEST_DIR"))
+}
@@ -0,0 +1,148 @@
+use std::fmt;
+
+use aoc_grid::{Grid, Point};
+use aoc_grid::automaton::{Automaton, Neighbourhood};
+use aoc_input::Malformed;
+use aoc_solution::{ParseError, Solution};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 4;
+
+    type Model<'a> = Rolls;
+    type Error = ParseError<ErrorKind>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(input: &str) -> Result<Rolls, Self::Error> {
+        Grid::parse(input, |c| match c {
+            '@' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(ErrorKind::UnknownCell),
+        })
+        .map_err(|e| ParseError::locate(input, e))
+    }
+
+    fn part1(rolls: &Rolls) -> usize {
+        Automaton::new(rolls.clone(), stays).step().changed
+    }
+
+    fn part2(rolls: &Rolls) -> usize {
+        removable(rolls)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ErrorKind {
+    Empty,
+    UnevenRows,
+    UnknownCell,
+}
+
+impl From<Malformed> for ErrorKind {
+    fn from(e: Malformed) -> Self {
+        match e {
+            Malformed::Empty => ErrorKind::Empty,
+            Malformed::UnevenRows => ErrorKind::UnevenRows,
+        }
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::Empty => write!(f, "empty grid"),
+            ErrorKind::UnevenRows => write!(f, "rows of different widths"),
+            ErrorKind::UnknownCell => write!(f, "unknown cell"),
+        }
+    }
+}
+
+// where the paper rolls are
+pub type Rolls = Grid<bool>;
+
+// A roll stays until a forklift can get to it, which takes fewer than four
+// rolls around it.
+pub fn stays(roll: &bool, around: &Neighbourhood<bool>) -> bool {
+    *roll && around.count8(|&r| r) >= 4
+}
+
+// How many rolls can be removed one after the other. Removing a roll only
+// lowers the counts around it, so the order does not matter: a queue holds
+// the rolls that can go, and only the neighbours of a removed one are looked
+// at again, a roll joining the queue when its count drops to three.
+pub fn removable(rolls: &Rolls) -> usize {
+    let mut rolls = rolls.clone();
+    let mut counts = Grid::from_fn(rolls.width(), rolls.height(), |p| {
+        rolls.neighbours8(p).filter(|&q| rolls[q]).count()
+    });
+    let mut queue: Vec<Point> = rolls.points().filter(|&p| rolls[p] && counts[p] < 4).collect();
+
+    let mut removed = 0;
+    while let Some(p) = queue.pop() {
+        rolls[p] = false;
+        removed += 1;
+        for q in rolls.neighbours8(p) {
+            if !rolls[q] {
+                continue;
+            }
+            counts[q] -= 1;
+            if counts[q] == 3 {
+                queue.push(q);
+            }
+        }
+    }
+    removed
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn examples() {
+        aoc_solution::check_examples::<Day04>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn removal_waves() {
+        let rolls = Day04::parse("@@@\n@@@\n@@@\n").unwrap();
+        let waves = Automaton::new(rolls, stays).run_to_fixpoint();
+        let removed: Vec<_> = waves.iter().map(|wave| wave.changed).collect();
+        assert_eq!(removed, vec![4, 4, 1]);
+        assert_eq!(removable(&Day04::parse("@@@\n@@@\n@@@\n").unwrap()), 9);
+    }
+
+    #[test]
+    fn removable_as_in_waves() {
+        // a floor mixing solid blocks, thin lines and scattered rolls
+        let rolls = Day04::parse(
+            "@@@@@..@.@@@@@@.@@@@\n\
+             @@@@@.@@@.@..@@@@@.@\n\
+             @@.@@@@.@@@@.@@@.@@@\n\
+             @@@@@..@@.@@@@@@@@@@\n\
+             .@@.@@@@@@@@..@@@@@.\n\
+             @@@@@@.@@.@@@@@@@@@@\n\
+             @.@@@@@@@@@.@@@@..@@\n\
+             @@@@.@@@@@@@@@.@@@@@\n\
+             @@@@@@@.@@.@@@@@@@.@\n\
+             .@@@@@@@@@@@@@.@@@@@\n",
+        )
+        .unwrap();
+        let waves = Automaton::new(rolls.clone(), stays).run_to_fixpoint();
+        let removed: usize = waves.iter().map(|wave| wave.changed).sum();
+        assert!(removed > 0);
+        assert_eq!(removable(&rolls), removed);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let e = Day04::parse("@@.\n.x@\n").err().unwrap();
+        assert_eq!((e.line, e.column, e.kind), (2, 2, ErrorKind::UnknownCell));
+
+        let e = Day04::parse("@@.\n.@\n").err().unwrap();
+        assert_eq!((e.line, e.kind), (2, ErrorKind::UnevenRows));
+    }
+}
@@ -0,0 +1,110 @@
+use std::{fmt, vec::Vec};
+
+use aoc_rangeset::RangeSet;
+use aoc_solution::{ParseError, Solution};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 5;
+
+    type Model<'a> = Inventory;
+    type Error = ParseError<ErrorKind>;
+    type Answer1 = usize;
+    type Answer2 = u128;
+
+    fn parse(input: &str) -> Result<Inventory, Self::Error> {
+        let blocks = aoc_input::blocks(input);
+        let block = |i: usize| blocks.get(i).copied().unwrap_or_default();
+        let number = |n: &str| {
+            n.parse::<u64>()
+                .map_err(|_| ParseError::at(input, n, ErrorKind::InvalidNumber))
+        };
+
+        let fresh = block(0)
+            .lines()
+            .map(|r_str| {
+                let (s, e) = r_str
+                    .split_once('-')
+                    .ok_or(ParseError::at(input, r_str, ErrorKind::NotARange))?;
+                let end = number(e)?
+                    .checked_add(1)
+                    .ok_or(ParseError::at(input, e, ErrorKind::EndAtMax))?;
+                Ok(number(s)?..end)
+            })
+            .collect::<Result<_, _>>()?;
+
+        let ids: Vec<_> = block(1)
+            .lines()
+            .map(number)
+            .collect::<Result<_, _>>()?;
+
+        Ok(Inventory { fresh, ids })
+    }
+
+    fn part1(inventory: &Inventory) -> usize {
+        let fresh = inventory.fresh.contains_each(&inventory.ids);
+        fresh.into_iter().filter(|&fresh| fresh).count()
+    }
+
+    fn part2(inventory: &Inventory) -> u128 {
+        inventory.fresh.len()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ErrorKind {
+    NotARange,
+    InvalidNumber,
+    EndAtMax,
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::NotARange => write!(f, "expected a `start-end` range"),
+            ErrorKind::InvalidNumber => write!(f, "invalid number"),
+            ErrorKind::EndAtMax => write!(f, "a range can not end at the largest id"),
+        }
+    }
+}
+
+// The ids covered by at least one range, merged, and the ids to check.
+#[derive(Debug)]
+pub struct Inventory {
+    pub fresh: RangeSet<u64>,
+    pub ids: Vec<u64>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn examples() {
+        aoc_solution::check_examples::<Day05>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let inventory = Day05::parse("3-5\n10-14\n\n1\n5\n").unwrap();
+        assert_eq!(inventory.fresh.ranges(), &[3..6, 10..15]);
+        assert_eq!(inventory.ids, vec![1, 5]);
+
+        let e = Day05::parse("3-5\n10\n\n1").unwrap_err();
+        assert_eq!((e.line, e.kind), (2, ErrorKind::NotARange));
+
+        let e = Day05::parse("3-5\n\n1\nfive").unwrap_err();
+        assert_eq!(
+            (e.line, e.column, e.text.as_str(), e.kind),
+            (4, 1, "five", ErrorKind::InvalidNumber)
+        );
+
+        let e = Day05::parse("3-18446744073709551615\n\n1").unwrap_err();
+        assert_eq!(
+            (e.line, e.column, e.kind),
+            (1, 3, ErrorKind::EndAtMax)
+        );
+    }
+}
@@ -0,0 +1,7 @@
+use std::process::ExitCode;
+
+use day06::Day06;
+
+fn main() -> ExitCode {
+    aoc_solution::bench::run::<Day06>(env!("CARGO_MANIFEST_DIR"))
+}
@@ -0,0 +1,132 @@
+use std::fmt;
+
+use aoc_input::Malformed;
+use aoc_solution::{ParseError, Solution, Unsolved};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2025;
+    const DAY: u8 = 6;
+
+    type Model<'a> = Vec<Problem>;
+    type Error = ParseError<ErrorKind>;
+    type Answer1 = u64;
+    type Answer2 = Unsolved;
+
+    fn parse(input: &str) -> Result<Vec<Problem>, Self::Error> {
+        let lines = aoc_input::lines(input).map_err(|e| ParseError::locate(input, e))?;
+        let (operators, numbers) = lines.split_last().unwrap();
+
+        let mut problems = operators
+            .split_ascii_whitespace()
+            .map(|token| match token {
+                "*" => Ok(Operator::Multiply),
+                "+" => Ok(Operator::Add),
+                _ => Err(ParseError::at(input, token, ErrorKind::UnknownOperator)),
+            })
+            .map(|operator| {
+                operator.map(|operator| Problem {
+                    operands: vec![],
+                    operator,
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        for line in numbers {
+            let row = aoc_input::numbers::<u64>(line)
+                .map_err(|e| ParseError::at(input, e.at, ErrorKind::InvalidNumber))?;
+            if row.len() != problems.len() {
+                return Err(ParseError::at(input, line, ErrorKind::ColumnMismatch));
+            }
+            for (problem, n) in problems.iter_mut().zip(row) {
+                problem.operands.push(n);
+            }
+        }
+        Ok(problems)
+    }
+
+    fn part1(problems: &Vec<Problem>) -> u64 {
+        problems.iter().fold(0, |total, problem| total + problem.solve())
+    }
+
+    fn part2(_: &Vec<Problem>) -> Unsolved {
+        Unsolved
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Operator {
+    Add,
+    Multiply,
+}
+
+// A column of the worksheet: its numbers from top to bottom, and what to do
+// with them.
+#[derive(Debug, PartialEq)]
+pub struct Problem {
+    pub operands: Vec<u64>,
+    pub operator: Operator,
+}
+
+impl Problem {
+    pub fn solve(&self) -> u64 {
+        let operands = self.operands.iter();
+        match self.operator {
+            Operator::Add => operands.sum(),
+            Operator::Multiply => operands.product(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ErrorKind {
+    Empty,
+    UnknownOperator,
+    InvalidNumber,
+    ColumnMismatch,
+}
+
+impl From<Malformed> for ErrorKind {
+    fn from(_: Malformed) -> Self {
+        ErrorKind::Empty
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::Empty => write!(f, "empty worksheet"),
+            ErrorKind::UnknownOperator => write!(f, "unknown operator"),
+            ErrorKind::InvalidNumber => write!(f, "invalid number"),
+            ErrorKind::ColumnMismatch => write!(f, "not as many numbers as operators"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn examples() {
+        aoc_solution::check_examples::<Day06>(env!("CARGO_MANIFEST_DIR"));
+    }
+
+    #[test]
+    fn problems() {
+        let problems = Day06::parse("123 328\n 45 64\n*   +\n").unwrap();
+        assert_eq!(
+            problems,
+            vec![
+                Problem { operands: vec![123, 45], operator: Operator::Multiply },
+                Problem { operands: vec![328, 64], operator: Operator::Add },
+            ]
+        );
+        assert_eq!(Day06::part1(&problems), 123 * 45 + 328 + 64);
+
+        let e = Day06::parse("1 2\n* -\n").unwrap_err();
+        assert_eq!((e.line, e.column, e.kind), (2, 3, ErrorKind::UnknownOperator));
+        let e = Day06::parse("1 2\n3\n* +\n").unwrap_err();
+        assert_eq!((e.line, e.kind), (2, ErrorKind::ColumnMismatch));
+    }
+}
@@ -0,0 +1,151 @@
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use clap::Args;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = "github.com/nebularnoise/advent-of-code aoc runner";
+
+#[derive(Args, Debug, Clone)]
+pub struct ClientArgs {
+    /// Website to talk to
+    #[arg(long, env = "AOC_BASE_URL", default_value = DEFAULT_BASE_URL)]
+    pub base_url: String,
+    /// Minimum number of seconds between two requests
+    #[arg(long, env = "AOC_THROTTLE", default_value_t = 5)]
+    pub throttle: u64,
+}
+
+// Talks to the website with the user's session token, spacing requests out by
+// at least the throttle, even across runs.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+    throttle: Duration,
+    last_request: PathBuf,
+}
+
+// Local state of the runner, kept out of git: `session` holds the token when
+// `AOC_SESSION` is not set.
+pub fn state_dir(root: &Path) -> PathBuf {
+    root.join(".aoc")
+}
+
+impl Client {
+    pub fn new(root: &Path, args: &ClientArgs) -> Result<Self, String> {
+        let session = match std::env::var("AOC_SESSION") {
+            Ok(session) => session,
+            Err(_) => {
+                let path = state_dir(root).join("session");
+                std::fs::read_to_string(&path).map_err(|e| {
+                    format!(
+                        "no session token: set AOC_SESSION or write it to `{}` ({})",
+                        path.display(),
+                        e
+                    )
+                })?
+            }
+        };
+        Ok(Self::with_session(root, args, &session))
+    }
+
+    pub fn with_session(root: &Path, args: &ClientArgs, session: &str) -> Self {
+        Client {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+            base_url: args.base_url.trim_end_matches('/').to_owned(),
+            session: session.trim().to_owned(),
+            throttle: Duration::from_secs(args.throttle),
+            last_request: state_dir(root).join("last-request"),
+        }
+    }
+
+    pub fn get(&self, path: &str) -> Result<String, String> {
+        self.wait_for_throttle()?;
+        let url = self.url(path);
+        read(
+            &url,
+            self.agent.get(&url).set("Cookie", &self.cookie()).call(),
+        )
+    }
+
+    pub fn post_form(&self, path: &str, form: &[(&str, &str)]) -> Result<String, String> {
+        self.wait_for_throttle()?;
+        let url = self.url(path);
+        let request = self.agent.post(&url).set("Cookie", &self.cookie());
+        read(&url, request.send_form(form))
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}/{}", self.base_url, path)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    fn wait_for_throttle(&self) -> Result<(), String> {
+        let last = std::fs::read_to_string(&self.last_request)
+            .ok()
+            .and_then(|millis| millis.trim().parse().ok())
+            .map(|millis| UNIX_EPOCH + Duration::from_millis(millis));
+        if let Some(wait) = last.and_then(|last| remaining(last, SystemTime::now(), self.throttle))
+        {
+            eprintln!("waiting {:.1?} before the next request", wait);
+            thread::sleep(wait);
+        }
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+        std::fs::create_dir_all(self.last_request.parent().unwrap())
+            .and_then(|()| std::fs::write(&self.last_request, now.as_millis().to_string()))
+            .map_err(|e| format!("could not record the request time: {}", e))
+    }
+}
+
+fn read(url: &str, response: Result<ureq::Response, ureq::Error>) -> Result<String, String> {
+    match response {
+        Ok(response) => response
+            .into_string()
+            .map_err(|e| format!("could not read the response of {}: {}", url, e)),
+        Err(ureq::Error::Status(404, _)) => {
+            Err(format!("{} not found, is the puzzle unlocked yet?", url))
+        }
+        Err(ureq::Error::Status(code @ (400 | 500), _)) => Err(format!(
+            "{} answered {}, is the session token still valid?",
+            url, code
+        )),
+        Err(ureq::Error::Status(code, _)) => Err(format!("{} answered {}", url, code)),
+        // the transport error already names the url
+        Err(ureq::Error::Transport(e)) => Err(format!("could not reach {}", e)),
+    }
+}
+
+// How long to wait before a request can be sent again.
+pub fn remaining(last: SystemTime, now: SystemTime, throttle: Duration) -> Option<Duration> {
+    (last + throttle)
+        .duration_since(now)
+        .ok()
+        .filter(|wait| !wait.is_zero())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::*;
+
+    #[test]
+    fn throttling() {
+        let last = UNIX_EPOCH + Duration::from_secs(100);
+        let throttle = Duration::from_secs(5);
+        assert_eq!(
+            remaining(last, last + Duration::from_secs(2), throttle),
+            Some(Duration::from_secs(3))
+        );
+        assert_eq!(remaining(last, last + throttle, throttle), None);
+        assert_eq!(
+            remaining(last, last + Duration::from_secs(60), throttle),
+            None
+        );
+    }
+}
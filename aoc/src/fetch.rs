@@ -0,0 +1,114 @@
+use std::path::Path;
+
+use clap::Args;
+
+use crate::client::{Client, ClientArgs};
+use crate::registry::Puzzle;
+
+#[derive(Args, Debug)]
+pub struct FetchArgs {
+    /// Puzzle year
+    pub year: u16,
+    /// Puzzle day
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    pub day: u8,
+    #[command(flatten)]
+    pub client: ClientArgs,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded,
+    Cached,
+}
+
+pub fn command(root: &Path, args: &FetchArgs) -> Result<(), String> {
+    let puzzle = Puzzle {
+        year: args.year,
+        day: args.day,
+    };
+    let dir = puzzle.dir(root);
+    if !dir.is_dir() {
+        return Err(format!("there is no `{}` crate yet", dir.display()));
+    }
+
+    let dest = dir.join("input.txt");
+    // checked before the client is set up, so cached inputs need no token
+    if dest.exists() {
+        println!("{} already there", dest.display());
+        return Ok(());
+    }
+    fetch(&Client::new(root, &args.client)?, &puzzle, &dest)?;
+    println!("{} downloaded", dest.display());
+    Ok(())
+}
+
+// Downloads an input unless the file already exists: inputs never change, so
+// they are only ever requested once.
+pub fn fetch(client: &Client, puzzle: &Puzzle, dest: &Path) -> Result<Fetched, String> {
+    if dest.exists() {
+        return Ok(Fetched::Cached);
+    }
+    let input = client.get(&format!("{}/day/{}/input", puzzle.year, puzzle.day))?;
+
+    // written aside first, so an interrupted download is not taken for the input
+    let partial = dest.with_extension("txt.part");
+    std::fs::write(&partial, input)
+        .and_then(|()| std::fs::rename(&partial, dest))
+        .map_err(|e| format!("could not write `{}`: {}", dest.display(), e))?;
+    Ok(Fetched::Downloaded)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::*;
+    use crate::fetch::*;
+    use crate::mock;
+
+    #[test]
+    fn downloads_once() {
+        let root = std::env::temp_dir().join(format!("aoc-fetch-{}", std::process::id()));
+        std::fs::create_dir_all(&root).unwrap();
+        let dest = root.join("input.txt");
+
+        let (base_url, server) = mock::serve(vec![(200, "1 2 3\n")]);
+        let args = ClientArgs {
+            base_url,
+            throttle: 0,
+        };
+        let client = Client::with_session(&root, &args, "s3cr3t\n");
+        let puzzle = Puzzle { year: 2023, day: 5 };
+
+        assert_eq!(fetch(&client, &puzzle, &dest), Ok(Fetched::Downloaded));
+        assert_eq!(fetch(&client, &puzzle, &dest), Ok(Fetched::Cached));
+        let input = std::fs::read_to_string(&dest).unwrap();
+        let requests = server.join().unwrap();
+        std::fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(input, "1 2 3\n");
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2023/day/5/input HTTP/1.1\r\n"));
+        assert!(requests[0].contains("Cookie: session=s3cr3t\r\n"));
+    }
+
+    #[test]
+    fn reports_locked_puzzles() {
+        let root = std::env::temp_dir().join(format!("aoc-locked-{}", std::process::id()));
+        std::fs::create_dir_all(&root).unwrap();
+        let dest = root.join("input.txt");
+
+        let (base_url, server) = mock::serve(vec![(404, "Not Found")]);
+        let args = ClientArgs {
+            base_url,
+            throttle: 0,
+        };
+        let client = Client::with_session(&root, &args, "s3cr3t");
+        let result = fetch(&client, &Puzzle { year: 2030, day: 1 }, &dest);
+        server.join().unwrap();
+        let downloaded = dest.exists();
+        std::fs::remove_dir_all(&root).unwrap();
+
+        assert!(result.unwrap_err().contains("unlocked"));
+        assert!(!downloaded);
+    }
+}
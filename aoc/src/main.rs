@@ -0,0 +1,329 @@
+mod client;
+mod fetch;
+#[cfg(test)]
+mod mock;
+mod registry;
+mod scaffold;
+mod submit;
+
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitCode};
+
+use aoc_solution::{EXIT_MISSING_ANSWERS, Format, Part};
+use clap::{Args, Parser, Subcommand};
+
+use registry::Puzzle;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Year {
+    All,
+    Only(u16),
+}
+
+impl std::str::FromStr for Year {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "all" => Ok(Year::All),
+            _ => s
+                .parse()
+                .map(Year::Only)
+                .map_err(|_| format!("expected a year or `all`, got `{}`", s)),
+        }
+    }
+}
+
+/// Run Advent of Code solvers from every year workspace
+#[derive(Parser, Debug)]
+#[command(
+    name = "aoc",
+    args_conflicts_with_subcommands = true,
+    subcommand_negates_reqs = true
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Cmd>,
+    #[command(flatten)]
+    run: RunArgs,
+}
+
+#[derive(Subcommand, Debug)]
+enum Cmd {
+    /// Download the input of a day into its directory, unless already there
+    Fetch(fetch::FetchArgs),
+    /// Send the answer of a part to the website and record its verdict
+    Submit(submit::SubmitArgs),
+    /// Create the crate of a new day and register it
+    New(scaffold::NewArgs),
+}
+
+#[derive(Args, Debug)]
+struct RunArgs {
+    /// Puzzle year, or `all` to run every registered day
+    #[arg(required = true)]
+    year: Option<Year>,
+    /// Puzzle day; every day of the year when omitted
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+    /// Puzzle part; both parts when omitted
+    part: Option<Part>,
+    /// Input file to give the solver instead of its `input.txt`, or `-` for stdin
+    #[arg(long)]
+    input: Option<PathBuf>,
+    /// Check every answer against the `.answers` file recorded next to its input
+    #[arg(long)]
+    check: bool,
+    /// How answers are printed; with `json` stdout only holds the records
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// Benchmark parsing and each part with criterion instead of solving once
+    #[arg(long, conflicts_with = "check")]
+    bench: bool,
+    /// Save the benchmark results under this baseline name
+    #[arg(long, requires = "bench")]
+    save_baseline: Option<String>,
+    /// Compare the benchmark results with this saved baseline
+    #[arg(long, requires = "bench", conflicts_with = "save_baseline")]
+    baseline: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Outcome {
+    Pass,
+    Fail,
+    Missing,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .to_path_buf();
+
+    let result = match cli.command {
+        Some(Cmd::Fetch(args)) => fetch::command(&root, &args),
+        Some(Cmd::Submit(args)) => submit::command(&root, &args),
+        Some(Cmd::New(args)) => scaffold::command(&root, &args),
+        None => return run_puzzles(&root, &cli.run),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run_puzzles(root: &Path, cli: &RunArgs) -> ExitCode {
+    let year = match cli.year.unwrap_or(Year::All) {
+        Year::All if cli.day.is_some() => {
+            eprintln!("a day can only be given together with a year");
+            return ExitCode::FAILURE;
+        }
+        Year::All => None,
+        Year::Only(y) => Some(y),
+    };
+
+    let puzzles = registry::select(year, cli.day);
+    if puzzles.is_empty() {
+        eprintln!("no registered solver matches the selection");
+        return ExitCode::FAILURE;
+    }
+    if cli.input.is_some() && puzzles.len() > 1 {
+        eprintln!("an input file can only be given for a single day");
+        return ExitCode::FAILURE;
+    }
+    // solvers run from their own directory, so relative paths are resolved here
+    let input = cli.input.clone().map(|path| match path.to_str() {
+        Some("-") => path,
+        _ => std::path::absolute(&path).unwrap_or(path),
+    });
+
+    let years: BTreeSet<u16> = puzzles.iter().map(|p| p.year).collect();
+    for y in years {
+        if let Err(e) = build_year(root, y, cli.bench) {
+            eprintln!("{}: {}", y, e);
+            return ExitCode::FAILURE;
+        }
+    }
+
+    let mut outcomes = vec![];
+    for puzzle in puzzles {
+        if cli.format == Format::Text {
+            println!("== {} day {:02} ==", puzzle.year, puzzle.day);
+        }
+        let outcome = run(root, &puzzle, cli, input.as_deref()).unwrap_or_else(|e| {
+            eprintln!("{} day {:02}: {}", puzzle.year, puzzle.day, e);
+            Outcome::Fail
+        });
+        outcomes.push((puzzle, outcome));
+    }
+
+    if cli.check {
+        print_summary(&outcomes, cli.format);
+    }
+    if outcomes.iter().any(|(_, o)| *o == Outcome::Fail) {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+// The summary is a diagnostic in JSON mode, the records already carry it.
+fn print_summary(outcomes: &[(Puzzle, Outcome)], format: Format) {
+    let print = |line: String| match format {
+        Format::Text => println!("{}", line),
+        Format::Json => eprintln!("{}", line),
+    };
+    print("== summary ==".to_owned());
+    for (puzzle, outcome) in outcomes {
+        let outcome = match outcome {
+            Outcome::Pass => "pass",
+            Outcome::Fail => "FAIL",
+            Outcome::Missing => "missing",
+        };
+        print(format!(
+            "{} day {:02}: {}",
+            puzzle.year, puzzle.day, outcome
+        ));
+    }
+    let count = |o| outcomes.iter().filter(|(_, outcome)| *outcome == o).count();
+    print(format!(
+        "{} passed, {} failed, {} missing",
+        count(Outcome::Pass),
+        count(Outcome::Fail),
+        count(Outcome::Missing)
+    ));
+}
+
+fn target_dir(root: &Path, year: u16) -> PathBuf {
+    root.join(year.to_string()).join("target")
+}
+
+// Builds every day of a year workspace at once, so running a whole year only
+// pays for one cargo invocation.
+fn build_year(root: &Path, year: u16, bench: bool) -> Result<(), String> {
+    let mut command = Command::new(std::env::var("CARGO").unwrap_or("cargo".to_owned()));
+    command
+        .arg("build")
+        .arg("--release")
+        .arg("--quiet")
+        .arg("--manifest-path")
+        .arg(root.join(year.to_string()).join("Cargo.toml"))
+        .arg("--target-dir")
+        .arg(target_dir(root, year));
+    if bench {
+        command.arg("--features").arg("aoc-solution/bench");
+    }
+    let status = command
+        .status()
+        .map_err(|e| format!("could not start cargo: {}", e))?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(format!("workspace failed to build ({})", status))
+    }
+}
+
+fn run(
+    root: &Path,
+    puzzle: &Puzzle,
+    cli: &RunArgs,
+    input: Option<&Path>,
+) -> Result<Outcome, String> {
+    let binary = target_dir(root, puzzle.year)
+        .join("release")
+        .join(puzzle.package());
+    let mut command = Command::new(&binary);
+    command.current_dir(puzzle.dir(root));
+    if let Some(input) = input {
+        command.arg(input);
+    }
+    if let Some(part) = cli.part {
+        command.arg("--part").arg(part.to_string());
+    }
+    if cli.check {
+        command.arg("--check");
+    }
+    if cli.format == Format::Json {
+        command.arg("--format").arg("json");
+    }
+    if cli.bench {
+        command.arg("--bench");
+    }
+    if let Some(name) = &cli.save_baseline {
+        command.arg("--save-baseline").arg(name);
+    }
+    if let Some(name) = &cli.baseline {
+        command.arg("--baseline").arg(name);
+    }
+    let status = command
+        .status()
+        .map_err(|e| format!("could not start {}: {}", binary.display(), e))?;
+
+    match status.code() {
+        _ if status.success() => Ok(Outcome::Pass),
+        Some(code) if cli.check && code == EXIT_MISSING_ANSWERS as i32 => Ok(Outcome::Missing),
+        _ => Err(format!("solver failed ({})", status)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn parse_year() {
+        assert_eq!("all".parse(), Ok(Year::All));
+        assert_eq!("2023".parse(), Ok(Year::Only(2023)));
+        assert!("twenty".parse::<Year>().is_err());
+    }
+
+    fn run_args(args: &[&str]) -> RunArgs {
+        let cli = Cli::try_parse_from([&["aoc"], args].concat()).unwrap();
+        assert!(cli.command.is_none());
+        cli.run
+    }
+
+    #[test]
+    fn cli() {
+        let cli = run_args(&["2023", "5", "2"]);
+        assert_eq!(cli.year, Some(Year::Only(2023)));
+        assert_eq!(cli.day, Some(5));
+        assert_eq!(cli.part, Some(Part::Two));
+        assert!(Cli::try_parse_from(["aoc", "2023", "5", "3"]).is_err());
+        assert!(Cli::try_parse_from(["aoc"]).is_err());
+        assert_eq!(run_args(&["all"]).year, Some(Year::All));
+        let cli = run_args(&["2025", "4", "--input", "-"]);
+        assert_eq!(cli.input, Some(PathBuf::from("-")));
+        assert!(run_args(&["all", "--check"]).check);
+        assert_eq!(run_args(&["all", "--format", "json"]).format, Format::Json);
+        let cli = run_args(&["2023", "--bench", "--save-baseline", "main"]);
+        assert_eq!(cli.save_baseline.as_deref(), Some("main"));
+        assert!(Cli::try_parse_from(["aoc", "2023", "--check", "--bench"]).is_err());
+    }
+
+    #[test]
+    fn subcommands() {
+        let cli = Cli::try_parse_from(["aoc", "fetch", "2023", "5"]).unwrap();
+        let Some(Cmd::Fetch(args)) = cli.command else {
+            panic!("expected the fetch command");
+        };
+        assert_eq!((args.year, args.day), (2023, 5));
+        assert!(Cli::try_parse_from(["aoc", "fetch", "2023", "26"]).is_err());
+
+        let cli = Cli::try_parse_from(["aoc", "submit", "2025", "3", "2", "--answer", "17"]);
+        let Some(Cmd::Submit(args)) = cli.unwrap().command else {
+            panic!("expected the submit command");
+        };
+        assert_eq!((args.year, args.day, args.part), (2025, 3, Part::Two));
+        assert_eq!(args.answer.as_deref(), Some("17"));
+        assert!(Cli::try_parse_from(["aoc", "submit", "2025", "3"]).is_err());
+
+        let cli = Cli::try_parse_from(["aoc", "new", "2025", "7"]).unwrap();
+        assert!(matches!(cli.command, Some(Cmd::New(_))));
+    }
+}
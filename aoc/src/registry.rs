@@ -0,0 +1,68 @@
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+}
+
+// Every solver the runner knows about, in the order `all` runs them.
+pub const PUZZLES: &[Puzzle] = &[
+    Puzzle { year: 2023, day: 1 },
+    Puzzle { year: 2023, day: 2 },
+    Puzzle { year: 2023, day: 3 },
+    Puzzle { year: 2023, day: 4 },
+    Puzzle { year: 2023, day: 5 },
+    Puzzle { year: 2023, day: 6 },
+    Puzzle { year: 2023, day: 7 },
+    Puzzle { year: 2023, day: 8 },
+    Puzzle { year: 2023, day: 9 },
+    Puzzle { year: 2025, day: 1 },
+    Puzzle { year: 2025, day: 2 },
+    Puzzle { year: 2025, day: 3 },
+    Puzzle { year: 2025, day: 4 },
+    Puzzle { year: 2025, day: 5 },
+    Puzzle { year: 2025, day: 6 },
+];
+
+impl Puzzle {
+    pub fn package(&self) -> String {
+        format!("day{:02}", self.day)
+    }
+
+    pub fn dir(&self, root: &Path) -> PathBuf {
+        root.join(self.year.to_string()).join(self.package())
+    }
+}
+
+pub fn select(year: Option<u16>, day: Option<u8>) -> Vec<Puzzle> {
+    PUZZLES
+        .iter()
+        .copied()
+        .filter(|p| year.is_none_or(|y| p.year == y))
+        .filter(|p| day.is_none_or(|d| p.day == d))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::registry::*;
+
+    #[test]
+    fn selection() {
+        assert_eq!(select(None, None).len(), PUZZLES.len());
+        assert_eq!(select(Some(2025), None).len(), 6);
+        assert_eq!(
+            select(Some(2023), Some(5)),
+            vec![Puzzle { year: 2023, day: 5 }]
+        );
+        assert!(select(Some(2024), None).is_empty());
+    }
+
+    #[test]
+    fn paths() {
+        let p = Puzzle { year: 2025, day: 4 };
+        assert_eq!(p.package(), "day04");
+        assert_eq!(p.dir(Path::new("/aoc")), Path::new("/aoc/2025/day04"));
+    }
+}
@@ -0,0 +1,297 @@
+use std::path::{Path, PathBuf};
+
+use clap::Args;
+
+use crate::registry::Puzzle;
+
+const MANIFEST_TEMPLATE: &str = include_str!("../templates/Cargo.toml.in");
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs.in");
+const MAIN_TEMPLATE: &str = include_str!("../templates/main.rs.in");
+const BENCH_TEMPLATE: &str = include_str!("../templates/bench.rs.in");
+
+const REGISTRY: &str = "aoc/src/registry.rs";
+const REGISTRY_START: &str = "pub const PUZZLES: &[Puzzle] = &[\n";
+
+#[derive(Args, Debug)]
+pub struct NewArgs {
+    /// Puzzle year
+    pub year: u16,
+    /// Puzzle day
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    pub day: u8,
+}
+
+pub fn command(root: &Path, args: &NewArgs) -> Result<(), String> {
+    let puzzle = Puzzle {
+        year: args.year,
+        day: args.day,
+    };
+    for path in scaffold(root, &puzzle)? {
+        println!("{}", path.strip_prefix(root).unwrap_or(&path).display());
+    }
+    println!(
+        "next: `cargo run -- fetch {} {}`, then fill in the example",
+        puzzle.year, puzzle.day
+    );
+    Ok(())
+}
+
+// Creates the crate of a day from the templates, with an empty example to
+// fill in, and adds it to its year workspace (created on the first day of a
+// year) and to the runner. Every file is first written next to where it goes,
+// and only renamed into place once all of them are, so a failure to write
+// leaves the tree as it was.
+pub fn scaffold(root: &Path, puzzle: &Puzzle) -> Result<Vec<PathBuf>, String> {
+    let dir = puzzle.dir(root);
+    if dir.exists() {
+        return Err(format!("`{}` already exists", dir.display()));
+    }
+
+    let mut writes = vec![];
+    let year_dir = root.join(puzzle.year.to_string());
+    let workspace = year_dir.join("Cargo.toml");
+    if workspace.exists() {
+        let manifest = read(&workspace)?;
+        writes.push((
+            workspace,
+            add_to_array(&manifest, "members", &puzzle.package())?,
+        ));
+    } else {
+        let manifest = format!(
+            "[workspace]\nmembers = [\n    \"{}\",\n]\nresolver = \"3\"\n",
+            puzzle.package()
+        );
+        writes.push((workspace, manifest));
+
+        // year workspaces stay out of the root one
+        let root_manifest = root.join("Cargo.toml");
+        let manifest = read(&root_manifest)?;
+        writes.push((
+            root_manifest,
+            add_to_array(&manifest, "exclude", &puzzle.year.to_string())?,
+        ));
+    }
+    let registry = root.join(REGISTRY);
+    writes.push((registry.clone(), register(&read(&registry)?, puzzle)?));
+
+    writes.push((
+        dir.join("Cargo.toml"),
+        instantiate(MANIFEST_TEMPLATE, puzzle),
+    ));
+    writes.push((
+        dir.join("src").join("lib.rs"),
+        instantiate(LIB_TEMPLATE, puzzle),
+    ));
+    writes.push((
+        dir.join("src").join("main.rs"),
+        instantiate(MAIN_TEMPLATE, puzzle),
+    ));
+    writes.push((
+        dir.join("benches").join("solution.rs"),
+        instantiate(BENCH_TEMPLATE, puzzle),
+    ));
+    for file in ["example.txt", "example.answers", "input.answers"] {
+        writes.push((dir.join(file), String::new()));
+    }
+
+    let created = if year_dir.exists() { dir } else { year_dir };
+    let written = writes.iter().try_for_each(|(path, content)| {
+        std::fs::create_dir_all(path.parent().unwrap())
+            .and_then(|()| std::fs::write(staged(path), content))
+            .map_err(|e| format!("could not write `{}`: {}", path.display(), e))
+    });
+    if let Err(e) = written {
+        for (path, _) in &writes {
+            let _ = std::fs::remove_file(staged(path));
+        }
+        let _ = std::fs::remove_dir_all(&created);
+        return Err(e);
+    }
+    for (path, _) in &writes {
+        std::fs::rename(staged(path), path)
+            .map_err(|e| format!("could not write `{}`: {}", path.display(), e))?;
+    }
+    Ok(writes.into_iter().map(|(path, _)| path).collect())
+}
+
+// Where a file is written before being renamed into place.
+fn staged(path: &Path) -> PathBuf {
+    let mut staged = path.as_os_str().to_owned();
+    staged.push(".new");
+    PathBuf::from(staged)
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    std::fs::read_to_string(path).map_err(|e| format!("could not read `{}`: {}", path.display(), e))
+}
+
+pub fn instantiate(template: &str, puzzle: &Puzzle) -> String {
+    template
+        .replace("{{package}}", &puzzle.package())
+        .replace("{{Solution}}", &format!("Day{:02}", puzzle.day))
+        .replace("{{year}}", &puzzle.year.to_string())
+        .replace("{{day}}", &puzzle.day.to_string())
+}
+
+// Adds an entry to a `key = [...]` array of strings in a manifest, keeping it
+// sorted and laid out on one or several lines as it was.
+fn add_to_array(manifest: &str, key: &str, entry: &str) -> Result<String, String> {
+    let prefix = format!("{} = [", key);
+    let start = manifest
+        .find(&prefix)
+        .ok_or(format!("no `{}` array in the manifest", key))?
+        + prefix.len();
+    let end = start
+        + manifest[start..]
+            .find(']')
+            .ok_or(format!("unterminated `{}` array in the manifest", key))?;
+
+    let list = &manifest[start..end];
+    let mut entries: Vec<_> = list
+        .split(',')
+        .map(|e| e.trim().trim_matches('"'))
+        .filter(|e| !e.is_empty())
+        .collect();
+    if !entries.contains(&entry) {
+        entries.push(entry);
+    }
+    entries.sort();
+
+    let list = if list.contains('\n') {
+        let lines: String = entries
+            .iter()
+            .map(|e| format!("\n    \"{}\",", e))
+            .collect();
+        lines + "\n"
+    } else {
+        let quoted: Vec<_> = entries.iter().map(|e| format!("\"{}\"", e)).collect();
+        quoted.join(", ")
+    };
+    Ok(format!(
+        "{}{}{}",
+        &manifest[..start],
+        list,
+        &manifest[end..]
+    ))
+}
+
+// Adds a puzzle to the `PUZZLES` list of the registry source, in order.
+fn register(source: &str, puzzle: &Puzzle) -> Result<String, String> {
+    let start = source
+        .find(REGISTRY_START)
+        .ok_or(format!("no `PUZZLES` list in `{}`", REGISTRY))?
+        + REGISTRY_START.len();
+    let end = start
+        + source[start..]
+            .find("];")
+            .ok_or(format!("unterminated `PUZZLES` list in `{}`", REGISTRY))?;
+
+    let mut puzzles = source[start..end]
+        .lines()
+        .map(|line| {
+            let fields = line
+                .trim()
+                .strip_prefix("Puzzle { year: ")?
+                .strip_suffix(" },")?;
+            let (year, day) = fields.split_once(", day: ")?;
+            Some(Puzzle {
+                year: year.parse().ok()?,
+                day: day.parse().ok()?,
+            })
+        })
+        .collect::<Option<Vec<_>>>()
+        .ok_or(format!(
+            "unexpected entry in the `PUZZLES` list of `{}`",
+            REGISTRY
+        ))?;
+    if !puzzles.contains(puzzle) {
+        puzzles.push(*puzzle);
+    }
+    puzzles.sort_by_key(|p| (p.year, p.day));
+
+    let list: String = puzzles
+        .iter()
+        .map(|p| format!("    Puzzle {{ year: {}, day: {} }},\n", p.year, p.day))
+        .collect();
+    Ok(format!("{}{}{}", &source[..start], list, &source[end..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::scaffold::*;
+
+    #[test]
+    fn arrays() {
+        let manifest =
+            "[workspace]\nmembers = [\n    \"day01\",\n    \"day03\",\n]\nresolver = \"3\"\n";
+        assert_eq!(
+            add_to_array(manifest, "members", "day02"),
+            Ok("[workspace]\nmembers = [\n    \"day01\",\n    \"day02\",\n    \"day03\",\n]\nresolver = \"3\"\n".to_owned())
+        );
+        assert_eq!(
+            add_to_array("exclude = [\"2023\", \"2025\"]\n", "exclude", "2024"),
+            Ok("exclude = [\"2023\", \"2024\", \"2025\"]\n".to_owned())
+        );
+        assert!(add_to_array(manifest, "exclude", "2024").is_err());
+    }
+
+    #[test]
+    fn registers_in_order() {
+        let source =
+            std::fs::read_to_string(Path::new(env!("CARGO_MANIFEST_DIR")).join("src/registry.rs"))
+                .unwrap();
+        let puzzle = Puzzle { year: 2024, day: 1 };
+        let registered = register(&source, &puzzle).unwrap();
+        assert!(registered.contains(
+            "    Puzzle { year: 2023, day: 9 },\n    Puzzle { year: 2024, day: 1 },\n    Puzzle { year: 2025, day: 1 },\n"
+        ));
+        assert_eq!(register(&registered, &puzzle), Ok(registered));
+    }
+
+    #[test]
+    fn scaffolds_a_day() {
+        let root = std::env::temp_dir().join(format!("aoc-new-{}", std::process::id()));
+        let repo = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+        for file in ["Cargo.toml", "2025/Cargo.toml", REGISTRY] {
+            std::fs::create_dir_all(root.join(file).parent().unwrap()).unwrap();
+            std::fs::copy(repo.join(file), root.join(file)).unwrap();
+        }
+
+        // a file that can not be written undoes the others
+        let before = std::fs::read_to_string(root.join("2025/Cargo.toml")).unwrap();
+        std::fs::create_dir(staged(&root.join(REGISTRY))).unwrap();
+        let failed = scaffold(&root, &Puzzle { year: 2025, day: 7 });
+        std::fs::remove_dir(staged(&root.join(REGISTRY))).unwrap();
+        let untouched = std::fs::read_to_string(root.join("2025/Cargo.toml")).unwrap() == before
+            && !root.join("2025/Cargo.toml.new").exists()
+            && !root.join("2025/day07").exists();
+
+        let created = scaffold(&root, &Puzzle { year: 2025, day: 7 });
+        let again = scaffold(&root, &Puzzle { year: 2025, day: 7 });
+        let new_year = scaffold(&root, &Puzzle { year: 2026, day: 1 });
+        let read = |file: &str| std::fs::read_to_string(root.join(file)).unwrap();
+        let (lib, workspace, new_workspace, manifest) = (
+            read("2025/day07/src/lib.rs"),
+            read("2025/Cargo.toml"),
+            read("2026/Cargo.toml"),
+            read("Cargo.toml"),
+        );
+        let registry = read(REGISTRY);
+        std::fs::remove_dir_all(&root).unwrap();
+
+        assert!(failed.unwrap_err().contains("could not write"));
+        assert!(untouched);
+        assert_eq!(created.unwrap().len(), 9);
+        assert!(again.unwrap_err().contains("already exists"));
+        assert_eq!(new_year.unwrap().len(), 10);
+        assert!(lib.contains("pub struct Day07;\n"));
+        assert!(lib.contains("const YEAR: u16 = 2025;\n    const DAY: u8 = 7;\n"));
+        assert!(!lib.contains("{{"));
+        assert!(workspace.contains("    \"day06\",\n    \"day07\",\n]"));
+        assert!(new_workspace.contains("members = [\n    \"day01\",\n]"));
+        assert!(manifest.contains("exclude = [\"2023\", \"2025\", \"2026\"]"));
+        assert!(registry.contains(
+            "    Puzzle { year: 2025, day: 7 },\n    Puzzle { year: 2026, day: 1 },\n];"
+        ));
+    }
+}
@@ -0,0 +1,424 @@
+use std::fmt::{self, Display};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use aoc_solution::answers::answers_path;
+use aoc_solution::{Answers, Part, Unsolved};
+use clap::Args;
+
+use crate::client::{Client, ClientArgs, state_dir};
+use crate::registry::{PUZZLES, Puzzle};
+
+#[derive(Args, Debug)]
+pub struct SubmitArgs {
+    /// Puzzle year
+    pub year: u16,
+    /// Puzzle day
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    pub day: u8,
+    /// Puzzle part
+    pub part: Part,
+    /// Answer to submit instead of the one the solver computes
+    #[arg(long)]
+    pub answer: Option<String>,
+    #[command(flatten)]
+    pub client: ClientArgs,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Right,
+    Wrong,
+    TooHigh,
+    TooLow,
+    // The answer came too soon after the previous one and was not looked at;
+    // holds the seconds left to wait.
+    Wait(u64),
+}
+
+impl Verdict {
+    pub fn from_response(page: &str) -> Result<Self, String> {
+        if page.contains("That's the right answer") {
+            Ok(Verdict::Right)
+        } else if page.contains("That's not the right answer") {
+            Ok(if page.contains("your answer is too high") {
+                Verdict::TooHigh
+            } else if page.contains("your answer is too low") {
+                Verdict::TooLow
+            } else {
+                Verdict::Wrong
+            })
+        } else if page.contains("You gave an answer too recently") {
+            Ok(Verdict::Wait(left_to_wait(page).unwrap_or(60)))
+        } else if page.contains("You don't seem to be solving the right level") {
+            Err("the website expects no answer for this part, is it already solved?".to_owned())
+        } else {
+            Err("the website answered something unexpected".to_owned())
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "right" => Some(Verdict::Right),
+            "wrong" => Some(Verdict::Wrong),
+            "too high" => Some(Verdict::TooHigh),
+            "too low" => Some(Verdict::TooLow),
+            _ => s
+                .strip_prefix("wait ")?
+                .strip_suffix('s')?
+                .parse()
+                .ok()
+                .map(Verdict::Wait),
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Right => write!(f, "right"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Wait(seconds) => write!(f, "wait {}s", seconds),
+        }
+    }
+}
+
+// Reads "You have 1m 5s left to wait" into seconds.
+fn left_to_wait(page: &str) -> Option<u64> {
+    let (_, rest) = page.split_once("You have ")?;
+    let (left, _) = rest.split_once(" left to wait")?;
+    left.split_whitespace()
+        .map(|amount| {
+            let unit = match amount.chars().last()? {
+                's' => 1,
+                'm' => 60,
+                'h' => 3600,
+                _ => return None,
+            };
+            let value: u64 = amount[..amount.len() - 1].parse().ok()?;
+            Some(value * unit)
+        })
+        .sum()
+}
+
+// One answer sent to the website, as a `time, part, verdict, answer` line of
+// tab-separated fields in the history of its day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    pub time: u64,
+    pub part: Part,
+    pub verdict: Verdict,
+    pub answer: String,
+}
+
+impl Attempt {
+    fn parse(line: &str) -> Option<Self> {
+        let mut fields = line.splitn(4, '\t');
+        Some(Attempt {
+            time: fields.next()?.parse().ok()?,
+            part: fields.next()?.strip_prefix("part")?.parse().ok()?,
+            verdict: Verdict::parse(fields.next()?)?,
+            answer: fields.next()?.to_owned(),
+        })
+    }
+}
+
+impl Display for Attempt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}\tpart{}\t{}\t{}",
+            self.time, self.part, self.verdict, self.answer
+        )
+    }
+}
+
+pub fn history_path(root: &Path, puzzle: &Puzzle) -> PathBuf {
+    state_dir(root)
+        .join("submissions")
+        .join(format!("{}-{}", puzzle.year, puzzle.package()))
+}
+
+fn load_history(path: &Path) -> Result<Vec<Attempt>, String> {
+    match std::fs::read_to_string(path) {
+        Ok(text) => text
+            .lines()
+            .map(|line| {
+                Attempt::parse(line).ok_or(format!(
+                    "`{}`: unreadable attempt `{}`",
+                    path.display(),
+                    line
+                ))
+            })
+            .collect(),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(vec![]),
+        Err(e) => Err(format!("could not read `{}`: {}", path.display(), e)),
+    }
+}
+
+fn record(path: &Path, attempt: &Attempt) -> Result<(), String> {
+    std::fs::create_dir_all(path.parent().unwrap())
+        .and_then(|()| {
+            std::fs::OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+        })
+        .and_then(|mut file| writeln!(file, "{}", attempt))
+        .map_err(|e| {
+            format!(
+                "could not record the attempt in `{}`: {}",
+                path.display(),
+                e
+            )
+        })
+}
+
+// What the website is known to say about an answer from the earlier attempts:
+// the same verdict again, wrong once another answer was right, and too high
+// or too low past an answer that already was.
+pub fn known(history: &[Attempt], part: Part, answer: &str) -> Option<Verdict> {
+    let judged = || {
+        history
+            .iter()
+            .filter(move |a| a.part == part && !matches!(a.verdict, Verdict::Wait(_)))
+    };
+    if let Some(attempt) = judged().find(|a| a.answer == answer) {
+        return Some(attempt.verdict);
+    }
+    let number = answer.parse::<i128>().ok();
+    judged().find_map(|attempt| {
+        let bound = attempt.answer.parse::<i128>().ok();
+        match (attempt.verdict, number, bound) {
+            (Verdict::Right, _, _) => Some(Verdict::Wrong),
+            (Verdict::TooHigh, Some(n), Some(high)) if n >= high => Some(Verdict::TooHigh),
+            (Verdict::TooLow, Some(n), Some(low)) if n <= low => Some(Verdict::TooLow),
+            _ => None,
+        }
+    })
+}
+
+// Until when the website refuses answers, going by the last attempt: the wait
+// it asked for, or the minute it locks a day for after a wrong answer.
+fn locked_until(history: &[Attempt]) -> Option<u64> {
+    let last = history.last()?;
+    match last.verdict {
+        Verdict::Right => None,
+        Verdict::Wait(seconds) => Some(last.time + seconds),
+        Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow => Some(last.time + 60),
+    }
+}
+
+// Sends an answer unless its verdict is already known or the website would
+// refuse it for now, and records what it answered.
+pub fn submit(
+    client: &Client,
+    history: &Path,
+    puzzle: &Puzzle,
+    part: Part,
+    answer: &str,
+    now: u64,
+) -> Result<Verdict, String> {
+    let attempts = load_history(history)?;
+    match known(&attempts, part, answer) {
+        Some(Verdict::Right) => return Ok(Verdict::Right),
+        Some(verdict) => {
+            return Err(format!(
+                "{} is known to be {}, not sending it again",
+                answer, verdict
+            ));
+        }
+        None => {}
+    }
+    if let Some(until) = locked_until(&attempts).filter(|&until| until > now) {
+        return Err(format!(
+            "the website refuses answers for another {}s",
+            until - now
+        ));
+    }
+
+    let page = client.post_form(
+        &format!("{}/day/{}/answer", puzzle.year, puzzle.day),
+        &[("level", &part.to_string()), ("answer", answer)],
+    )?;
+    let verdict = Verdict::from_response(&page)?;
+    let attempt = Attempt {
+        time: now,
+        part,
+        verdict,
+        answer: answer.to_owned(),
+    };
+    record(history, &attempt)?;
+    Ok(verdict)
+}
+
+pub fn command(root: &Path, args: &SubmitArgs) -> Result<(), String> {
+    let puzzle = Puzzle {
+        year: args.year,
+        day: args.day,
+    };
+    if !PUZZLES.contains(&puzzle) {
+        return Err(format!(
+            "no registered solver for {} day {}",
+            args.year, args.day
+        ));
+    }
+    let answer = match &args.answer {
+        Some(answer) => answer.trim().to_owned(),
+        None => solve(root, &puzzle, args.part)?,
+    };
+    if answer.is_empty() || answer == Unsolved.to_string() {
+        return Err(format!("part {} has no answer to submit", args.part));
+    }
+
+    let client = Client::new(root, &args.client)?;
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let history = history_path(root, &puzzle);
+    let verdict = submit(&client, &history, &puzzle, args.part, &answer, now)?;
+    println!(
+        "{} day {:02} part {}: {} is {}",
+        puzzle.year, puzzle.day, args.part, answer, verdict
+    );
+    if verdict != Verdict::Right {
+        return Err("the answer was not accepted".to_owned());
+    }
+
+    // accepted answers become known answers for `--check`
+    let path = answers_path(&puzzle.dir(root).join("input.txt"));
+    let mut answers = Answers::load(&path).map_err(|e| e.to_string())?;
+    if answers.get(args.part) != Some(answer.as_str()) {
+        answers.set(args.part, &answer);
+        std::fs::write(&path, answers.to_string())
+            .map_err(|e| format!("could not write `{}`: {}", path.display(), e))?;
+    }
+    Ok(())
+}
+
+// Runs the solver of one part as the runner does and reads the answer from
+// its JSON record.
+fn solve(root: &Path, puzzle: &Puzzle, part: Part) -> Result<String, String> {
+    crate::build_year(root, puzzle.year, false)?;
+    let binary = crate::target_dir(root, puzzle.year)
+        .join("release")
+        .join(puzzle.package());
+    let output = Command::new(&binary)
+        .current_dir(puzzle.dir(root))
+        .arg("--part")
+        .arg(part.to_string())
+        .arg("--format")
+        .arg("json")
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|e| format!("could not start {}: {}", binary.display(), e))?;
+    if !output.status.success() {
+        return Err(format!("solver failed ({})", output.status));
+    }
+    let record: serde_json::Value = serde_json::from_slice(&output.stdout)
+        .map_err(|e| format!("unexpected solver output: {}", e))?;
+    record["answer"]
+        .as_str()
+        .map(str::to_owned)
+        .ok_or("the solver printed no answer".to_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mock;
+    use crate::submit::*;
+
+    #[test]
+    fn verdicts() {
+        let page = |text| format!("<main>\n<article><p>{}</p></article>\n</main>", text);
+        let right = page("That's the right answer! You are one gold star closer.");
+        assert_eq!(Verdict::from_response(&right), Ok(Verdict::Right));
+        let high = page("That's not the right answer; your answer is too high.");
+        assert_eq!(Verdict::from_response(&high), Ok(Verdict::TooHigh));
+        let low = page("That's not the right answer; your answer is too low.");
+        assert_eq!(Verdict::from_response(&low), Ok(Verdict::TooLow));
+        let wrong = page("That's not the right answer. If you're stuck, ...");
+        assert_eq!(Verdict::from_response(&wrong), Ok(Verdict::Wrong));
+        let wait = page("You gave an answer too recently. You have 1m 5s left to wait.");
+        assert_eq!(Verdict::from_response(&wait), Ok(Verdict::Wait(65)));
+        let done = page("You don't seem to be solving the right level.");
+        assert!(Verdict::from_response(&done).is_err());
+
+        for verdict in [Verdict::TooLow, Verdict::Wait(30)] {
+            assert_eq!(Verdict::parse(&verdict.to_string()), Some(verdict));
+        }
+    }
+
+    fn attempt(part: Part, verdict: Verdict, answer: &str) -> Attempt {
+        Attempt {
+            time: 1000,
+            part,
+            verdict,
+            answer: answer.to_owned(),
+        }
+    }
+
+    #[test]
+    fn known_verdicts() {
+        let history = [
+            attempt(Part::One, Verdict::TooHigh, "500"),
+            attempt(Part::One, Verdict::TooLow, "100"),
+            attempt(Part::One, Verdict::Wait(30), "300"),
+            attempt(Part::Two, Verdict::Right, "7"),
+        ];
+        assert_eq!(known(&history, Part::One, "500"), Some(Verdict::TooHigh));
+        assert_eq!(known(&history, Part::One, "600"), Some(Verdict::TooHigh));
+        assert_eq!(known(&history, Part::One, "50"), Some(Verdict::TooLow));
+        assert_eq!(known(&history, Part::One, "300"), None);
+        assert_eq!(known(&history, Part::One, "abc"), None);
+        assert_eq!(known(&history, Part::Two, "7"), Some(Verdict::Right));
+        assert_eq!(known(&history, Part::Two, "8"), Some(Verdict::Wrong));
+
+        let line = history[2].to_string();
+        assert_eq!(line, "1000\tpart1\twait 30s\t300");
+        assert_eq!(Attempt::parse(&line).as_ref(), Some(&history[2]));
+        assert_eq!(locked_until(&history[..1]), Some(1060));
+        assert_eq!(locked_until(&history[..3]), Some(1030));
+        assert_eq!(locked_until(&history), None);
+    }
+
+    #[test]
+    fn never_resubmits() {
+        let root = std::env::temp_dir().join(format!("aoc-submit-{}", std::process::id()));
+        let puzzle = Puzzle { year: 2023, day: 5 };
+        let history = history_path(&root, &puzzle);
+
+        let (base_url, server) = mock::serve(vec![(
+            200,
+            "<article><p>That's not the right answer; your answer is too low.</p></article>",
+        )]);
+        let args = ClientArgs {
+            base_url,
+            throttle: 0,
+        };
+        let client = Client::with_session(&root, &args, "s3cr3t");
+        let first = submit(&client, &history, &puzzle, Part::Two, "123", 1000);
+        let again = submit(&client, &history, &puzzle, Part::Two, "100", 2000);
+        let locked = submit(&client, &history, &puzzle, Part::Two, "200", 1010);
+        let attempts = load_history(&history);
+        let requests = server.join().unwrap();
+        std::fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(first, Ok(Verdict::TooLow));
+        assert!(again.unwrap_err().contains("too low"));
+        assert!(locked.unwrap_err().contains("50s"));
+        assert_eq!(
+            attempts,
+            Ok(vec![attempt(Part::Two, Verdict::TooLow, "123")])
+        );
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("POST /2023/day/5/answer HTTP/1.1\r\n"));
+        assert!(requests[0].contains("Cookie: session=s3cr3t\r\n"));
+        assert!(requests[0].ends_with("\r\n\r\nlevel=2&answer=123"));
+    }
+}
@@ -0,0 +1,163 @@
+use crate::{Grid, Point};
+
+// What a rule sees of the grid around the cell it decides the next state of.
+pub struct Neighbourhood<'a, T> {
+    grid: &'a Grid<T>,
+    point: Point,
+}
+
+impl<'a, T> Neighbourhood<'a, T> {
+    pub fn point(&self) -> Point {
+        self.point
+    }
+
+    pub fn neighbours4(&self) -> impl Iterator<Item = &'a T> + use<'a, T> {
+        let grid = self.grid;
+        grid.neighbours4(self.point).map(move |q| &grid[q])
+    }
+
+    pub fn neighbours8(&self) -> impl Iterator<Item = &'a T> + use<'a, T> {
+        let grid = self.grid;
+        grid.neighbours8(self.point).map(move |q| &grid[q])
+    }
+
+    // How many of the eight neighbours match.
+    pub fn count8(&self, matches: impl Fn(&T) -> bool) -> usize {
+        self.neighbours8().filter(|&cell| matches(cell)).count()
+    }
+}
+
+// How a step went: the 1-based number of the step and how many cells it
+// changed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Wave {
+    pub step: usize,
+    pub changed: usize,
+}
+
+// A grid whose cells all move to their next state at once, the rule deciding
+// it from the cell and its neighbourhood before the step.
+pub struct Automaton<T, R> {
+    grid: Grid<T>,
+    rule: R,
+    steps: usize,
+}
+
+impl<T, R> Automaton<T, R>
+where
+    T: PartialEq,
+    R: FnMut(&T, &Neighbourhood<T>) -> T,
+{
+    pub fn new(grid: Grid<T>, rule: R) -> Self {
+        Self {
+            grid,
+            rule,
+            steps: 0,
+        }
+    }
+
+    pub fn grid(&self) -> &Grid<T> {
+        &self.grid
+    }
+
+    pub fn into_grid(self) -> Grid<T> {
+        self.grid
+    }
+
+    // Steps taken so far, including one that found nothing left to change.
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    pub fn step(&mut self) -> Wave {
+        let grid = &self.grid;
+        let rule = &mut self.rule;
+        let next = Grid::from_fn(grid.width(), grid.height(), |point| {
+            rule(&grid[point], &Neighbourhood { grid, point })
+        });
+        let changed = next
+            .cells()
+            .iter()
+            .zip(self.grid.cells())
+            .filter(|(a, b)| a != b)
+            .count();
+        self.grid = next;
+        self.steps += 1;
+        Wave {
+            step: self.steps,
+            changed,
+        }
+    }
+
+    // Steps until `stop` holds after a wave, or until nothing changes, and
+    // returns the waves that changed something.
+    pub fn run_until(&mut self, mut stop: impl FnMut(&Wave, &Grid<T>) -> bool) -> Vec<Wave> {
+        let mut waves = vec![];
+        loop {
+            let wave = self.step();
+            if wave.changed == 0 {
+                return waves;
+            }
+            waves.push(wave);
+            if stop(&wave, &self.grid) {
+                return waves;
+            }
+        }
+    }
+
+    // At most `steps` more steps, fewer if nothing changes any more.
+    pub fn run(&mut self, steps: usize) -> Vec<Wave> {
+        let last = self.steps + steps;
+        if steps == 0 {
+            return vec![];
+        }
+        self.run_until(|wave, _| wave.step == last)
+    }
+
+    pub fn run_to_fixpoint(&mut self) -> Vec<Wave> {
+        self.run_until(|_, _| false)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::automaton::*;
+
+    fn life(alive: &bool, around: &Neighbourhood<bool>) -> bool {
+        matches!((alive, around.count8(|&a| a)), (true, 2) | (_, 3))
+    }
+
+    fn wave(step: usize, changed: usize) -> Wave {
+        Wave { step, changed }
+    }
+
+    #[test]
+    fn blinker() {
+        let grid = Grid::parse(".....\n..#..\n..#..\n..#..\n.....\n", |c| {
+            Ok::<_, aoc_input::Malformed>(c == '#')
+        })
+        .unwrap();
+        let mut automaton = Automaton::new(grid.clone(), life);
+
+        assert_eq!(automaton.step(), wave(1, 4));
+        assert_eq!(automaton.grid().row(2), &[false, true, true, true, false]);
+        assert_eq!(automaton.run(3).len(), 3);
+        assert_eq!(automaton.steps(), 4);
+        assert_eq!(automaton.grid(), &grid);
+        let waves = automaton.run_until(|_, grid| grid[Point::new(1, 2)]);
+        assert_eq!(waves, vec![wave(5, 4)]);
+    }
+
+    #[test]
+    fn fixpoint() {
+        // a cell dies once its count of live neighbours drops below two
+        let grid = Grid::from_cells(4, vec![true; 4]);
+        let mut automaton = Automaton::new(grid, |&alive, around: &Neighbourhood<bool>| {
+            alive && around.count8(|&a| a) >= 2
+        });
+        let waves = automaton.run_to_fixpoint();
+        assert_eq!(waves, vec![wave(1, 2), wave(2, 2)]);
+        assert_eq!(automaton.steps(), 3);
+        assert!(automaton.into_grid().cells().iter().all(|&alive| !alive));
+    }
+}
@@ -0,0 +1,277 @@
+use std::ops::{Index, IndexMut};
+
+use aoc_input::{ErrorAt, Malformed};
+
+pub mod automaton;
+
+// A cell position, `x` growing rightwards along a row and `y` downwards from
+// the first row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Point {
+    pub fn new(x: usize, y: usize) -> Self {
+        Self { x, y }
+    }
+
+    // The point `dx`, `dy` away, unless that goes past the top or left edge.
+    pub fn offset(self, dx: isize, dy: isize) -> Option<Point> {
+        Some(Point {
+            x: self.x.checked_add_signed(dx)?,
+            y: self.y.checked_add_signed(dy)?,
+        })
+    }
+}
+
+// Offsets of the orthogonal neighbours, then of all eight, in reading order.
+pub const NEIGHBOURS_4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+pub const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+// A rectangular map of cells, stored row after row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+
+    // Each cell computed from its position, in reading order.
+    pub fn from_fn(width: usize, height: usize, mut cell: impl FnMut(Point) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point { x, y }))
+            .map(&mut cell)
+            .collect();
+        Self {
+            cells,
+            width,
+            height,
+        }
+    }
+
+    // Cells given row after row; panics unless they make full rows.
+    pub fn from_cells(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "{} cells do not make rows of {}",
+            cells.len(),
+            width
+        );
+        Self {
+            height: cells.len() / width,
+            cells,
+            width,
+        }
+    }
+
+    // Reads a character map, each character turned into a cell by `cell`. An
+    // error is located at the character it was returned for.
+    pub fn parse<K: From<Malformed>>(
+        input: &str,
+        mut cell: impl FnMut(char) -> Result<T, K>,
+    ) -> Result<Self, ErrorAt<&str, K>> {
+        let rows = aoc_input::grid(input).map_err(|e| ErrorAt::new(e.at, e.kind.into()))?;
+        let mut cells = vec![];
+        for row in &rows {
+            for (i, c) in row.char_indices() {
+                let at = &row[i..i + c.len_utf8()];
+                cells.push(cell(c).map_err(|kind| ErrorAt::new(at, kind))?);
+            }
+        }
+        Ok(Self {
+            width: cells.len() / rows.len(),
+            height: rows.len(),
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        p.x < self.width && p.y < self.height
+    }
+
+    pub fn get(&self, p: Point) -> Option<&T> {
+        self.contains(p)
+            .then(|| &self.cells[p.y * self.width + p.x])
+    }
+
+    pub fn get_mut(&mut self, p: Point) -> Option<&mut T> {
+        if self.contains(p) {
+            Some(&mut self.cells[p.y * self.width + p.x])
+        } else {
+            None
+        }
+    }
+
+    // Every position, in reading order.
+    pub fn points(&self) -> impl Iterator<Item = Point> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point { x, y }))
+    }
+
+    // Every cell along with its position, in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(&self.cells)
+    }
+
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} out of the grid", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    // The orthogonal neighbours of `p` that are in the grid.
+    pub fn neighbours4(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        self.around(p, &NEIGHBOURS_4)
+    }
+
+    // The neighbours of `p` diagonals included that are in the grid.
+    pub fn neighbours8(&self, p: Point) -> impl Iterator<Item = Point> + '_ {
+        self.around(p, &NEIGHBOURS_8)
+    }
+
+    fn around(
+        &self,
+        p: Point,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Point> + '_ {
+        offsets
+            .iter()
+            .filter_map(move |&(dx, dy)| p.offset(dx, dy))
+            .filter(|&q| self.contains(q))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(f).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl Grid<char> {
+    pub fn chars(input: &str) -> Result<Self, ErrorAt<&str, Malformed>> {
+        Self::parse(input, Ok)
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point) -> &T {
+        self.get(p)
+            .unwrap_or_else(|| panic!("{:?} out of a {}x{} grid", p, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, p: Point) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("{:?} out of a {}x{} grid", p, width, height))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[derive(Debug, PartialEq)]
+    enum Kind {
+        Malformed(Malformed),
+        UnknownCell,
+    }
+
+    impl From<Malformed> for Kind {
+        fn from(e: Malformed) -> Self {
+            Kind::Malformed(e)
+        }
+    }
+
+    #[test]
+    fn parses_maps() {
+        let grid = Grid::chars("ab\ncd\nef\n").unwrap();
+        assert_eq!((grid.width(), grid.height()), (2, 3));
+        assert_eq!(grid[Point::new(1, 2)], 'f');
+        assert_eq!(grid.get(Point::new(2, 0)), None);
+
+        let input = "..@\n.x.";
+        let cell = |c| match c {
+            '@' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(Kind::UnknownCell),
+        };
+        let e = Grid::parse(input, cell).unwrap_err();
+        assert_eq!((e.at, e.kind), ("x", Kind::UnknownCell));
+        assert!(std::ptr::eq(e.at, &input[5..6]));
+        let e = Grid::parse("..\n.", cell).unwrap_err();
+        assert_eq!(e.kind, Kind::Malformed(Malformed::UnevenRows));
+    }
+
+    #[test]
+    fn neighbours() {
+        let grid = Grid::new(3, 2, 0);
+        let corner: Vec<_> = grid.neighbours8(Point::new(0, 0)).collect();
+        assert_eq!(
+            corner,
+            vec![Point::new(1, 0), Point::new(0, 1), Point::new(1, 1)]
+        );
+        assert_eq!(grid.neighbours8(Point::new(1, 1)).count(), 5);
+        let edge: Vec<_> = grid.neighbours4(Point::new(2, 1)).collect();
+        assert_eq!(edge, vec![Point::new(2, 0), Point::new(1, 1)]);
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let mut grid = Grid::from_cells(3, (0..6).collect());
+        grid[Point::new(0, 1)] = 9;
+        assert_eq!(grid.row(1), &[9, 4, 5]);
+        assert_eq!(grid.column(2).copied().collect::<Vec<_>>(), vec![2, 5]);
+        assert_eq!(grid.rows().count(), 2);
+        assert_eq!(grid.points().last(), Some(Point::new(2, 1)));
+        let doubled = grid.map(|n| n * 2);
+        assert_eq!(doubled.iter().nth(4), Some((Point::new(1, 1), &8)));
+    }
+}
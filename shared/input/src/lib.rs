@@ -0,0 +1,201 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+// A failure that only knows the offending slice; it gets a line and column
+// once put back in the context of the whole input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ErrorAt<I, K> {
+    pub at: I,
+    pub kind: K,
+}
+
+impl<I, K> ErrorAt<I, K> {
+    pub fn new(at: I, kind: K) -> Self {
+        Self { at, kind }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub path: PathBuf,
+    pub source: io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.path == Path::new("-") {
+            write!(f, "could not read input from stdin: {}", self.source)
+        } else {
+            write!(
+                f,
+                "could not read input file `{}`: {}",
+                self.path.display(),
+                self.source
+            )
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+// Reads the whole puzzle input, `-` standing for stdin, normalized.
+pub fn read_input(path: &Path) -> Result<String, InputError> {
+    let read = if path == Path::new("-") {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input).map(|_| input)
+    } else {
+        std::fs::read_to_string(path)
+    };
+    read.map(|input| normalize(&input))
+        .map_err(|source| InputError {
+            path: path.to_owned(),
+            source,
+        })
+}
+
+// The input as solvers expect it, whatever saved it: no byte order mark, `\n`
+// line endings, and a single newline at the end unless it is empty. Spaces
+// are kept, some inputs are laid out in columns.
+pub fn normalize(text: &str) -> String {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let mut text = text.replace("\r\n", "\n");
+    text.truncate(text.trim_end_matches('\n').len());
+    if !text.is_empty() {
+        text.push('\n');
+    }
+    text
+}
+
+// What the helpers below find wrong with the shape of an input; days turn it
+// into their own error kind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Malformed {
+    Empty,
+    UnevenRows,
+}
+
+impl Display for Malformed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Malformed::Empty => write!(f, "empty input"),
+            Malformed::UnevenRows => write!(f, "row not as wide as the first one"),
+        }
+    }
+}
+
+// The lines of an input holding at least one.
+pub fn lines(input: &str) -> Result<Vec<&str>, ErrorAt<&str, Malformed>> {
+    let lines: Vec<_> = input.lines().collect();
+    if lines.is_empty() {
+        return Err(ErrorAt::new(input, Malformed::Empty));
+    }
+    Ok(lines)
+}
+
+// The sections of an input, separated by blank lines.
+pub fn blocks(input: &str) -> Vec<&str> {
+    input
+        .split("\n\n")
+        .map(|block| block.trim_matches('\n'))
+        .filter(|block| !block.is_empty())
+        .collect()
+}
+
+// The rows of a character map, all as wide as the first one.
+pub fn grid(input: &str) -> Result<Vec<&str>, ErrorAt<&str, Malformed>> {
+    let rows = lines(input)?;
+    let width = rows[0].chars().count();
+    if width == 0 {
+        return Err(ErrorAt::new(rows[0], Malformed::Empty));
+    }
+    if let Some(row) = rows.iter().find(|row| row.chars().count() != width) {
+        return Err(ErrorAt::new(row, Malformed::UnevenRows));
+    }
+    Ok(rows)
+}
+
+// The numbers of a whitespace-separated list, failing at the first one that
+// does not parse.
+pub fn numbers<T: FromStr>(text: &str) -> Result<Vec<T>, ErrorAt<&str, T::Err>> {
+    text.split_whitespace()
+        .map(|n| n.parse().map_err(|e| ErrorAt::new(n, e)))
+        .collect()
+}
+
+// Lets nom parsers report errors as located slices.
+#[cfg(feature = "nom")]
+mod nom_support {
+    use nom::error::{ErrorKind, FromExternalError};
+
+    use crate::ErrorAt;
+
+    impl<'a, K: From<ErrorKind>> nom::error::ParseError<&'a str> for ErrorAt<&'a str, K> {
+        fn from_error_kind(input: &'a str, kind: ErrorKind) -> Self {
+            ErrorAt::new(input, kind.into())
+        }
+
+        fn append(_input: &'a str, _kind: ErrorKind, other: Self) -> Self {
+            other
+        }
+    }
+
+    impl<'a, K, E: Into<K>> FromExternalError<&'a str, E> for ErrorAt<&'a str, K> {
+        fn from_external_error(input: &'a str, _kind: ErrorKind, e: E) -> Self {
+            ErrorAt::new(input, e.into())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::ParseIntError;
+
+    use crate::*;
+
+    #[test]
+    fn normalizes() {
+        assert_eq!(normalize("\u{feff}a\r\nb\r\n\r\n\n"), "a\nb\n");
+        assert_eq!(normalize("a  \n  b"), "a  \n  b\n");
+        assert_eq!(normalize("\n\n"), "");
+    }
+
+    #[test]
+    fn missing_input_file() {
+        let e = read_input(Path::new("does/not/exist.txt")).unwrap_err();
+        assert_eq!(e.source.kind(), io::ErrorKind::NotFound);
+        assert!(
+            e.to_string()
+                .starts_with("could not read input file `does/not/exist.txt`")
+        );
+    }
+
+    #[test]
+    fn splits_blocks() {
+        assert_eq!(blocks("1-2\n3-4\n\n5\n\n\n6\n"), vec!["1-2\n3-4", "5", "6"]);
+        assert!(blocks("").is_empty());
+    }
+
+    #[test]
+    fn grids() {
+        assert_eq!(grid("..@\n@.@\n"), Ok(vec!["..@", "@.@"]));
+        assert_eq!(grid(""), Err(ErrorAt::new("", Malformed::Empty)));
+        assert_eq!(grid("\n.."), Err(ErrorAt::new("", Malformed::Empty)));
+        let e = grid("...\n..\n...").unwrap_err();
+        assert_eq!((e.at, e.kind), ("..", Malformed::UnevenRows));
+        assert_eq!(lines("a\nb"), Ok(vec!["a", "b"]));
+    }
+
+    #[test]
+    fn number_lists() {
+        assert_eq!(numbers::<i64>(" 0 -3\t6 "), Ok(vec![0, -3, 6]));
+        let e: ErrorAt<&str, ParseIntError> = numbers::<u8>("1 256 3").unwrap_err();
+        assert_eq!(e.at, "256");
+    }
+}
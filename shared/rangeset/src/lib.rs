@@ -0,0 +1,289 @@
+use std::fmt::Debug;
+use std::ops::{Range, RangeInclusive};
+
+// The integers a set can hold.
+pub trait Integer: Copy + Ord + Debug {
+    fn checked_succ(self) -> Option<Self>;
+
+    // How many integers lie from `self` up to `end`, counted wide enough
+    // for any range of any of them.
+    fn distance(self, end: Self) -> u128;
+}
+
+macro_rules! integers {
+    ($($t:ty),*) => {
+        $(impl Integer for $t {
+            fn checked_succ(self) -> Option<Self> {
+                self.checked_add(1)
+            }
+
+            fn distance(self, end: Self) -> u128 {
+                end.abs_diff(self) as u128
+            }
+        })*
+    };
+}
+
+integers!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+// A set of integers stored as half-open ranges, kept sorted, non-empty, and
+// apart from each other: ranges that overlap or touch are merged into one.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RangeSet<N> {
+    ranges: Vec<Range<N>>,
+}
+
+impl<N> Default for RangeSet<N> {
+    fn default() -> Self {
+        Self { ranges: vec![] }
+    }
+}
+
+impl<N: Integer> RangeSet<N> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // `start..=end`; none if the end is the largest value of `N`, which has
+    // no half-open equivalent.
+    pub fn inclusive(range: RangeInclusive<N>) -> Option<Self> {
+        let (start, end) = range.into_inner();
+        Some(Self::from(start..end.checked_succ()?))
+    }
+
+    pub fn ranges(&self) -> &[Range<N>] {
+        &self.ranges
+    }
+
+    pub fn into_ranges(self) -> Vec<Range<N>> {
+        self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    // How many integers are in the set.
+    pub fn len(&self) -> u128 {
+        self.ranges.iter().map(|r| r.start.distance(r.end)).sum()
+    }
+
+    // Found by binary search over the ranges.
+    pub fn contains(&self, n: N) -> bool {
+        let after = self.ranges.partition_point(|r| r.start <= n);
+        after > 0 && n < self.ranges[after - 1].end
+    }
+
+    // Whether each of `ns` is in the set, in order. Sorted queries are
+    // answered in a single walk along the ranges, others one by one.
+    pub fn contains_each(&self, ns: &[N]) -> Vec<bool> {
+        if !ns.is_sorted() {
+            return ns.iter().map(|&n| self.contains(n)).collect();
+        }
+        let mut ranges = self.ranges.iter().peekable();
+        ns.iter()
+            .map(|&n| {
+                while ranges.next_if(|r| r.end <= n).is_some() {}
+                ranges.peek().is_some_and(|r| r.start <= n)
+            })
+            .collect()
+    }
+
+    // Merges `range` with only the ranges it overlaps or touches, found by
+    // binary search.
+    pub fn insert(&mut self, range: Range<N>) {
+        if range.is_empty() {
+            return;
+        }
+        let first = self.ranges.partition_point(|r| r.end < range.start);
+        let after = self.ranges.partition_point(|r| r.start <= range.end);
+        let merged = if first < after {
+            let (a, b) = (&self.ranges[first], &self.ranges[after - 1]);
+            a.start.min(range.start)..b.end.max(range.end)
+        } else {
+            range
+        };
+        self.ranges.splice(first..after, [merged]);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        self.ranges.iter().chain(&other.ranges).cloned().collect()
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (
+            self.ranges.iter().peekable(),
+            other.ranges.iter().peekable(),
+        );
+        let mut ranges = vec![];
+        while let (Some(x), Some(y)) = (a.peek(), b.peek()) {
+            let overlap = x.start.max(y.start)..x.end.min(y.end);
+            if !overlap.is_empty() {
+                ranges.push(overlap);
+            }
+            // the range ending first can not overlap anything further
+            if x.end < y.end {
+                a.next();
+            } else {
+                b.next();
+            }
+        }
+        Self { ranges }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        match (self.ranges.first(), self.ranges.last()) {
+            (Some(first), Some(last)) => {
+                self.intersection(&other.complement(first.start..last.end))
+            }
+            _ => Self::new(),
+        }
+    }
+
+    // What of `bounds` is not in the set.
+    pub fn complement(&self, bounds: Range<N>) -> Self {
+        let mut ranges = vec![];
+        let mut start = bounds.start;
+        for r in &self.ranges {
+            if r.start >= bounds.end {
+                break;
+            }
+            if r.start > start {
+                ranges.push(start..r.start);
+            }
+            start = start.max(r.end);
+        }
+        if start < bounds.end {
+            ranges.push(start..bounds.end);
+        }
+        Self { ranges }
+    }
+
+    fn normalize(&mut self) {
+        self.ranges.retain(|r| !r.is_empty());
+        self.ranges.sort_by_key(|r| r.start);
+        let mut merged: Vec<Range<N>> = Vec::with_capacity(self.ranges.len());
+        for r in self.ranges.drain(..) {
+            match merged.last_mut() {
+                Some(last) if r.start <= last.end => last.end = last.end.max(r.end),
+                _ => merged.push(r),
+            }
+        }
+        self.ranges = merged;
+    }
+}
+
+impl<N: Integer> From<Range<N>> for RangeSet<N> {
+    fn from(range: Range<N>) -> Self {
+        let mut set = Self {
+            ranges: vec![range],
+        };
+        set.normalize();
+        set
+    }
+}
+
+impl<N: Integer> FromIterator<Range<N>> for RangeSet<N> {
+    fn from_iter<I: IntoIterator<Item = Range<N>>>(ranges: I) -> Self {
+        let mut set = Self {
+            ranges: ranges.into_iter().collect(),
+        };
+        set.normalize();
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn set(ranges: &[Range<i32>]) -> RangeSet<i32> {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    fn normalizes() {
+        let s = set(&[10..15, 3..6, 5..8, 8..9, 12..14, 20..20]);
+        assert_eq!(s.ranges(), &[3..9, 10..15]);
+        assert_eq!(s.len(), 11);
+        assert_eq!(RangeSet::inclusive(3..=5), Some(RangeSet::from(3..6)));
+        assert_eq!(RangeSet::inclusive(5..=u64::MAX), None);
+        assert!(RangeSet::from(4..4).is_empty());
+        assert_eq!(RangeSet::<i8>::from(-100..100).len(), 200);
+        assert_eq!(RangeSet::from(i128::MIN..i128::MAX).len(), u128::MAX);
+
+        let mut s = RangeSet::new();
+        s.insert(0..2);
+        s.insert(-3..0);
+        assert_eq!(s.clone().into_ranges(), vec![-3..2]);
+        for (range, ranges) in [
+            (5..5, vec![-3..2]),
+            (10..12, vec![-3..2, 10..12]),
+            (4..6, vec![-3..2, 4..6, 10..12]),
+            (-10..-5, vec![-10..-5, -3..2, 4..6, 10..12]),
+            (3..4, vec![-10..-5, -3..2, 3..6, 10..12]),
+            (1..10, vec![-10..-5, -3..12]),
+            (-20..20, vec![-20..20]),
+        ] {
+            s.insert(range);
+            assert_eq!(s.ranges(), ranges);
+        }
+
+        // inserting one by one agrees with building the set at once
+        let inserts = [
+            50..60,
+            10..20,
+            30..40,
+            20..30,
+            55..58,
+            70..70,
+            65..75,
+            0..5,
+            5..6,
+            40..41,
+            -5..100,
+            200..210,
+            150..160,
+            99..150,
+        ];
+        let mut s = RangeSet::new();
+        for (i, range) in inserts.iter().enumerate() {
+            s.insert(range.clone());
+            assert_eq!(s, set(&inserts[..=i]));
+        }
+        assert_eq!(s.ranges(), &[-5..160, 200..210]);
+    }
+
+    #[test]
+    fn membership() {
+        let s = set(&[3..6, 10..15]);
+        let members: Vec<_> = (0..17).filter(|&n| s.contains(n)).collect();
+        assert_eq!(members, vec![3, 4, 5, 10, 11, 12, 13, 14]);
+        assert!(!RangeSet::new().contains(0));
+
+        let ns = [0, 3, 3, 5, 6, 9, 10, 14, 15, 16];
+        let expected: Vec<_> = ns.iter().map(|&n| s.contains(n)).collect();
+        assert_eq!(s.contains_each(&ns), expected);
+        let mut reversed = ns;
+        reversed.reverse();
+        let expected: Vec<_> = expected.into_iter().rev().collect();
+        assert_eq!(s.contains_each(&reversed), expected);
+    }
+
+    #[test]
+    fn operations() {
+        let a = set(&[0..10, 20..30]);
+        let b = set(&[5..25, 28..40]);
+        assert_eq!(a.union(&b), RangeSet::from(0..40));
+        assert_eq!(a.intersection(&b), set(&[5..10, 20..25, 28..30]));
+        assert_eq!(a.difference(&b), set(&[0..5, 25..28]));
+        assert_eq!(b.difference(&a), set(&[10..20, 30..40]));
+        assert_eq!(a.complement(-5..35), set(&[-5..0, 10..20, 30..35]));
+        assert_eq!(a.complement(12..18), RangeSet::from(12..18));
+        assert_eq!(a.complement(2..8), RangeSet::new());
+        assert_eq!(RangeSet::new().difference(&a), RangeSet::new());
+    }
+}
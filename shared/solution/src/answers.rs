@@ -0,0 +1,137 @@
+use std::fmt::{self, Display};
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::{InputError, Part};
+
+// Known answers for an input, kept next to it: `input.txt` is checked against
+// `input.answers`, which holds one `partN: answer` line per solved part.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+pub fn answers_path(input: &Path) -> PathBuf {
+    input.with_extension("answers")
+}
+
+impl Answers {
+    pub fn parse(text: &str) -> Self {
+        let mut answers = Answers::default();
+        for (key, answer) in text.lines().filter_map(|l| l.split_once(':')) {
+            let answer = Some(answer.trim().to_owned());
+            match key.trim() {
+                "part1" => answers.part1 = answer,
+                "part2" => answers.part2 = answer,
+                _ => {}
+            }
+        }
+        answers
+    }
+
+    // A missing file only means no answer is known yet.
+    pub fn load(path: &Path) -> Result<Self, InputError> {
+        match std::fs::read_to_string(path) {
+            Ok(text) => Ok(Self::parse(&text)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(source) => Err(InputError {
+                path: path.to_owned(),
+                source,
+            }),
+        }
+    }
+
+    pub fn get(&self, part: Part) -> Option<&str> {
+        match part {
+            Part::One => self.part1.as_deref(),
+            Part::Two => self.part2.as_deref(),
+        }
+    }
+
+    pub fn set(&mut self, part: Part, answer: &str) {
+        let answer = Some(answer.to_owned());
+        match part {
+            Part::One => self.part1 = answer,
+            Part::Two => self.part2 = answer,
+        }
+    }
+}
+
+// Written back in the format `parse` reads.
+impl Display for Answers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for part in Part::ALL {
+            if let Some(answer) = self.get(part) {
+                writeln!(f, "part{}: {}", part, answer)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Check {
+    Pass,
+    Fail { expected: String },
+    Missing,
+}
+
+impl Check {
+    pub fn of(answer: &str, expected: Option<&str>) -> Self {
+        match expected {
+            None => Check::Missing,
+            Some(expected) if expected == answer => Check::Pass,
+            Some(expected) => Check::Fail {
+                expected: expected.to_owned(),
+            },
+        }
+    }
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Check::Pass => write!(f, "ok"),
+            Check::Fail { expected } => write!(f, "FAILED, expected {}", expected),
+            Check::Missing => write!(f, "no known answer"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::*;
+
+    #[test]
+    fn parses_answers() {
+        let answers = Answers::parse("part1: 54644\npart2:53348\n");
+        assert_eq!(answers.get(Part::One), Some("54644"));
+        assert_eq!(answers.get(Part::Two), Some("53348"));
+
+        let mut answers = Answers::parse("part1: 4277556\n");
+        assert_eq!(answers.get(Part::Two), None);
+        answers.set(Part::Two, "42");
+        assert_eq!(answers.to_string(), "part1: 4277556\npart2: 42\n");
+        assert_eq!(Answers::parse(&answers.to_string()), answers);
+    }
+
+    #[test]
+    fn checks() {
+        assert_eq!(Check::of("1", Some("1")), Check::Pass);
+        assert_eq!(Check::of("1", None), Check::Missing);
+        assert_eq!(Check::of("1", Some("2")).to_string(), "FAILED, expected 2");
+    }
+
+    #[test]
+    fn paths() {
+        assert_eq!(
+            answers_path(Path::new("2023/day05/input.txt")),
+            Path::new("2023/day05/input.answers")
+        );
+        assert_eq!(
+            Answers::load(Path::new("does/not/exist.answers")).unwrap(),
+            Answers::default()
+        );
+    }
+}
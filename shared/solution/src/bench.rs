@@ -0,0 +1,62 @@
+use std::error::Error;
+use std::hint::black_box;
+use std::path::Path;
+use std::process::ExitCode;
+
+use criterion::Criterion;
+
+use crate::{Args, Part, Solution, read_input};
+
+// Entry point of the `benches/` target of every day, which `cargo bench` runs:
+// the real input next to the crate, with criterion taking its own options
+// (filters, baselines, ...) from the command line.
+pub fn run<S: Solution>(dir: impl AsRef<Path>) -> ExitCode {
+    let result = read_input(&dir.as_ref().join("input.txt"))
+        .map_err(|e| e.into())
+        .and_then(|input| {
+            let mut c = Criterion::default().configure_from_args();
+            measure::<S>(&mut c, &input, &Part::ALL)
+        });
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// The same measures for the `--bench` flag of a solver, on its input and
+// with the baselines and parts it was given.
+pub fn run_with<S: Solution>(input: &str, args: &Args) -> Result<(), Box<dyn Error>> {
+    let mut c = Criterion::default();
+    if let Some(name) = &args.save_baseline {
+        c = c.save_baseline(name.clone());
+    }
+    if let Some(name) = &args.baseline {
+        c = c.retain_baseline(name.clone(), true);
+    }
+    measure::<S>(&mut c, input, &args.parts())
+}
+
+// Measures parsing and each of `parts` separately, in a group named after the
+// puzzle so the baselines of every day live side by side.
+fn measure<S: Solution>(
+    c: &mut Criterion,
+    input: &str,
+    parts: &[Part],
+) -> Result<(), Box<dyn Error>> {
+    let model = S::parse(input)?;
+
+    let mut group = c.benchmark_group(format!("{}-day{:02}", S::YEAR, S::DAY));
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(input))));
+    for part in parts {
+        match part {
+            Part::One => group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&model)))),
+            Part::Two => group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&model)))),
+        };
+    }
+    group.finish();
+    c.final_summary();
+    Ok(())
+}
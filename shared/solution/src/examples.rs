@@ -0,0 +1,54 @@
+use std::path::{Path, PathBuf};
+
+use crate::answers::{Answers, answers_path};
+use crate::{Part, Solution, read_input, solve};
+
+// The worked examples of a day: every `example*.txt` of its directory, each
+// with the answers the puzzle statement gives in a matching `.answers` file.
+pub fn examples(dir: &Path) -> Vec<PathBuf> {
+    let mut examples: Vec<_> = std::fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| {
+            path.extension().is_some_and(|ext| ext == "txt")
+                && path
+                    .file_stem()
+                    .and_then(|stem| stem.to_str())
+                    .is_some_and(|stem| stem.starts_with("example"))
+        })
+        .collect();
+    examples.sort();
+    examples
+}
+
+// Solves every example of the day in `dir` and panics with a report of the
+// mismatches. A day without any example answer fails too, so new days start
+// from a red test.
+pub fn check_examples<S: Solution>(dir: impl AsRef<Path>) {
+    let mut checked = 0;
+    let mut failures = vec![];
+    for example in examples(dir.as_ref()) {
+        let name = example.file_name().unwrap().to_string_lossy().into_owned();
+        let input = read_input(&example).unwrap_or_else(|e| panic!("{}", e));
+        let expected = Answers::load(&answers_path(&example)).unwrap_or_else(|e| panic!("{}", e));
+        let model = S::parse(&input).unwrap_or_else(|e| panic!("{}: {}", name, e));
+
+        for part in Part::ALL {
+            let Some(expected) = expected.get(part) else {
+                continue;
+            };
+            let (answer, _) = solve::<S>(&model, part);
+            if answer != expected {
+                failures.push(format!(
+                    "{} part {}: got {}, expected {}",
+                    name, part, answer, expected
+                ));
+            }
+            checked += 1;
+        }
+    }
+
+    assert!(checked > 0, "no example answer to check against");
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
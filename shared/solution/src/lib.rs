@@ -0,0 +1,343 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use clap::{Parser, ValueEnum};
+use serde_json::json;
+
+pub mod answers;
+#[cfg(feature = "bench")]
+pub mod bench;
+pub mod examples;
+pub mod parse;
+
+pub use answers::{Answers, Check};
+pub use aoc_input::{InputError, read_input};
+pub use examples::check_examples;
+pub use parse::{ErrorAt, ParseError};
+
+// Exit code of a checked run where nothing failed but some answers are unknown.
+pub const EXIT_MISSING_ANSWERS: u8 = 2;
+
+// A puzzle solver, split in the stages every day goes through: the input is
+// parsed once into a model, which both parts then work from independently.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    type Model<'a>;
+    type Error: Error + 'static;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(input: &str) -> Result<Self::Model<'_>, Self::Error>;
+    fn part1(model: &Self::Model<'_>) -> Self::Answer1;
+    fn part2(model: &Self::Model<'_>) -> Self::Answer2;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl std::str::FromStr for Part {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Part::One),
+            "2" => Ok(Part::Two),
+            _ => Err(format!("expected part 1 or 2, got `{}`", s)),
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+// Answer of a part that has not been solved yet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unsolved")
+    }
+}
+
+pub fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+// Runs one part against an already parsed model, returning its rendered answer.
+pub fn solve<S: Solution>(model: &S::Model<'_>, part: Part) -> (String, Duration) {
+    match part {
+        Part::One => {
+            let (answer, elapsed) = timed(|| S::part1(model));
+            (answer.to_string(), elapsed)
+        }
+        Part::Two => {
+            let (answer, elapsed) = timed(|| S::part2(model));
+            (answer.to_string(), elapsed)
+        }
+    }
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    /// Answers and timings for people
+    Text,
+    /// One JSON record per part, diagnostics going to stderr
+    Json,
+}
+
+#[derive(Parser, Debug)]
+pub struct Args {
+    /// Puzzle input file, or `-` to read it from stdin
+    #[arg(default_value = "input.txt")]
+    pub input: PathBuf,
+    /// Only run this part; both parts when omitted
+    #[arg(long)]
+    pub part: Option<Part>,
+    /// Compare the answers with the ones recorded next to the input
+    #[arg(long)]
+    pub check: bool,
+    /// How answers are printed on stdout
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    pub format: Format,
+    /// Benchmark parsing and the selected parts instead of solving once
+    #[arg(long, conflicts_with = "check")]
+    pub bench: bool,
+    /// Save the benchmark results under this baseline name
+    #[arg(long, requires = "bench")]
+    pub save_baseline: Option<String>,
+    /// Compare the benchmark results with this saved baseline
+    #[arg(long, requires = "bench", conflicts_with = "save_baseline")]
+    pub baseline: Option<String>,
+}
+
+impl Args {
+    pub fn parts(&self) -> Vec<Part> {
+        match self.part {
+            Some(part) => vec![part],
+            None => Part::ALL.to_vec(),
+        }
+    }
+}
+
+// Solves the selected parts, returning how they compare to the known answers
+// when checking.
+pub fn run<S: Solution>(input: &str, args: &Args) -> Result<Vec<Check>, Box<dyn Error>> {
+    if args.bench {
+        #[cfg(feature = "bench")]
+        return bench::run_with::<S>(input, args).map(|()| vec![]);
+        #[cfg(not(feature = "bench"))]
+        return Err("benchmarks need the solver built with `--features aoc-solution/bench`".into());
+    }
+
+    let expected = if args.check {
+        if args.input == Path::new("-") {
+            return Err("answers can only be checked for an input file".into());
+        }
+        Some(Answers::load(&answers::answers_path(&args.input))?)
+    } else {
+        None
+    };
+
+    let (model, elapsed) = timed(|| S::parse(input));
+    let model = model?;
+    match args.format {
+        Format::Text => println!("parse: {:?}", elapsed),
+        Format::Json => eprintln!("parse: {:?}", elapsed),
+    }
+
+    let mut checks = vec![];
+    for part in args.parts() {
+        let (answer, elapsed) = solve::<S>(&model, part);
+        let check = expected.as_ref().map(|e| Check::of(&answer, e.get(part)));
+        match (args.format, &check) {
+            (Format::Text, None) => println!("pt{}: {} ({:?})", part, answer, elapsed),
+            (Format::Text, Some(check)) => {
+                println!("pt{}: {} ({:?}) {}", part, answer, elapsed, check)
+            }
+            (Format::Json, _) => {
+                println!("{}", record::<S>(part, &answer, elapsed, check.as_ref()))
+            }
+        }
+        checks.extend(check);
+    }
+    Ok(checks)
+}
+
+// The machine-readable result of a part, `elapsed` being in seconds.
+pub fn record<S: Solution>(
+    part: Part,
+    answer: &str,
+    elapsed: Duration,
+    check: Option<&Check>,
+) -> serde_json::Value {
+    let mut record = json!({
+        "year": S::YEAR,
+        "day": S::DAY,
+        "part": part.number(),
+        "answer": answer,
+        "elapsed": elapsed.as_secs_f64(),
+    });
+    match check {
+        Some(Check::Fail { expected }) => {
+            record["check"] = json!("fail");
+            record["expected"] = json!(expected);
+        }
+        Some(Check::Pass) => record["check"] = json!("pass"),
+        Some(Check::Missing) => record["check"] = json!("missing"),
+        None => {}
+    }
+    record
+}
+
+// Entry point shared by every day's binary.
+pub fn main<S: Solution>() -> ExitCode {
+    main_with::<S>(Args::parse())
+}
+
+// The same, for a day whose binary reads arguments of its own along with
+// these.
+pub fn main_with<S: Solution>(args: Args) -> ExitCode {
+    let result = read_input(&args.input)
+        .map_err(|e| e.into())
+        .and_then(|input| run::<S>(&input, &args));
+    match result {
+        Ok(checks) if checks.iter().any(|c| matches!(c, Check::Fail { .. })) => ExitCode::FAILURE,
+        Ok(checks) if checks.contains(&Check::Missing) => ExitCode::from(EXIT_MISSING_ANSWERS),
+        Ok(_) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        const YEAR: u16 = 2000;
+        const DAY: u8 = 1;
+
+        type Model<'a> = Vec<i32>;
+        type Error = std::num::ParseIntError;
+        type Answer1 = i32;
+        type Answer2 = Unsolved;
+
+        fn parse(input: &str) -> Result<Vec<i32>, Self::Error> {
+            input.split_whitespace().map(str::parse).collect()
+        }
+        fn part1(model: &Vec<i32>) -> i32 {
+            model.iter().sum()
+        }
+        fn part2(_model: &Vec<i32>) -> Unsolved {
+            Unsolved
+        }
+    }
+
+    #[test]
+    fn parts() {
+        assert_eq!("1".parse(), Ok(Part::One));
+        assert_eq!("2".parse(), Ok(Part::Two));
+        assert!("3".parse::<Part>().is_err());
+        assert_eq!(Part::Two.to_string(), "2");
+    }
+
+    #[test]
+    fn input_argument() {
+        let args = Args::try_parse_from(["day01"]).unwrap();
+        assert_eq!(args.input, Path::new("input.txt"));
+        let args = Args::try_parse_from(["day01", "-", "--part", "2"]).unwrap();
+        assert_eq!(args.input, Path::new("-"));
+        assert_eq!(args.part, Some(Part::Two));
+        assert!(!args.check);
+
+        let args = Args::try_parse_from(["day01", "--bench", "--baseline", "main"]).unwrap();
+        assert_eq!(args.baseline.as_deref(), Some("main"));
+        assert!(Args::try_parse_from(["day01", "--save-baseline", "main"]).is_err());
+        assert!(Args::try_parse_from(["day01", "--bench", "--check"]).is_err());
+    }
+
+    #[test]
+    fn solves_each_part() {
+        let model = Sum::parse("1 2 3").unwrap();
+        assert_eq!(solve::<Sum>(&model, Part::One).0, "6");
+        assert_eq!(solve::<Sum>(&model, Part::Two).0, "unsolved");
+        assert!(Sum::parse("1 two").is_err());
+    }
+
+    #[test]
+    fn checks_examples() {
+        let dir = std::env::temp_dir().join(format!("aoc-examples-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let write = |name: &str, text: &str| std::fs::write(dir.join(name), text).unwrap();
+
+        let no_answers = std::panic::catch_unwind(|| check_examples::<Sum>(&dir));
+        write("example.txt", "1 2 3");
+        write("example.answers", "part1: 6\n");
+        write("notes.txt", "not an example");
+        check_examples::<Sum>(&dir);
+        write("example2.txt", "1 2");
+        write("example2.answers", "part1: 4\n");
+        let wrong = std::panic::catch_unwind(|| check_examples::<Sum>(&dir));
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert!(no_answers.is_err());
+        assert!(wrong.is_err());
+    }
+
+    #[test]
+    fn json_records() {
+        let elapsed = Duration::from_millis(1500);
+        assert_eq!(
+            record::<Sum>(Part::One, "6", elapsed, None).to_string(),
+            r#"{"answer":"6","day":1,"elapsed":1.5,"part":1,"year":2000}"#
+        );
+        let fail = Check::Fail {
+            expected: "7".to_owned(),
+        };
+        let record = record::<Sum>(Part::Two, "6", elapsed, Some(&fail));
+        assert_eq!(
+            (&record["check"], &record["expected"]),
+            (&json!("fail"), &json!("7"))
+        );
+
+        let args = Args::try_parse_from(["day01", "--format", "json"]).unwrap();
+        assert_eq!(args.format, Format::Json);
+    }
+
+    #[test]
+    fn checks_stdin_refused() {
+        let args = Args::try_parse_from(["day01", "-", "--check"]).unwrap();
+        assert!(run::<Sum>("1 2 3", &args).is_err());
+    }
+}
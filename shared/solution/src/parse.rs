@@ -0,0 +1,191 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+
+// A parse failure located in the puzzle input, 1-based like editors report
+// them. `kind` is the day-specific reason.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError<K> {
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub kind: K,
+}
+
+pub use aoc_input::ErrorAt;
+
+impl<K> ParseError<K> {
+    // `at` must be a slice of `input`.
+    pub fn at(input: &str, at: &str, kind: K) -> Self {
+        let offset = (at.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+        debug_assert!(offset <= input.len(), "error location outside of the input");
+        let before = &input[..offset.min(input.len())];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        Self {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+            text: at.to_owned(),
+            kind,
+        }
+    }
+
+    // The kind of `e` may be any that converts into this one, such as the
+    // shape errors of `aoc_input`.
+    pub fn locate(input: &str, e: ErrorAt<&str, impl Into<K>>) -> Self {
+        Self::at(input, e.at, e.kind.into())
+    }
+}
+
+impl<K: Display> Display for ParseError<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.kind
+        )?;
+        if self.text.is_empty() {
+            write!(f, " at end of line")
+        } else {
+            write!(f, " `{}`", self.text)
+        }
+    }
+}
+
+impl<K: fmt::Debug + Display> Error for ParseError<K> {}
+
+// The offending token at the start of what a parser could not consume: a
+// word or number, or else a single character.
+pub fn token(rest: &str) -> &str {
+    match rest.chars().next() {
+        None => rest,
+        Some(c) if !c.is_alphanumeric() => &rest[..c.len_utf8()],
+        Some(_) => {
+            let end = rest.find(|c: char| !c.is_alphanumeric());
+            &rest[..end.unwrap_or(rest.len())]
+        }
+    }
+}
+
+#[cfg(feature = "nom")]
+pub use nom_support::number;
+
+#[cfg(feature = "nom")]
+mod nom_support {
+    use std::str::FromStr;
+
+    use nom::character::complete::digit1;
+    use nom::error::ErrorKind;
+
+    use crate::parse::{ErrorAt, ParseError, token};
+
+    // Decimal digits parsed into `T`. Digits that do not fit are a failure
+    // rather than an error, so no other branch gets to reinterpret them.
+    pub fn number<T, K>(input: &str) -> nom::IResult<&str, T, ErrorAt<&str, K>>
+    where
+        T: FromStr,
+        K: From<ErrorKind> + From<T::Err>,
+    {
+        let (rest, digits) = digit1(input)?;
+        match digits.parse() {
+            Ok(n) => Ok((rest, n)),
+            Err(e) => Err(nom::Err::Failure(ErrorAt::new(digits, e.into()))),
+        }
+    }
+
+    impl<K: From<ErrorKind>> ParseError<K> {
+        pub fn from_nom(input: &str, e: nom::Err<ErrorAt<&str, K>>) -> Self {
+            match e {
+                nom::Err::Error(e) | nom::Err::Failure(e) => Self::at(input, token(e.at), e.kind),
+                nom::Err::Incomplete(_) => {
+                    Self::at(input, &input[input.len()..], ErrorKind::Eof.into())
+                }
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use std::num::ParseIntError;
+
+        use nom::character::complete::multispace1;
+        use nom::combinator::all_consuming;
+        use nom::multi::separated_list1;
+
+        use crate::parse::nom_support::*;
+
+        #[derive(Debug, PartialEq)]
+        enum Kind {
+            InvalidNumber,
+            Unexpected,
+        }
+
+        impl From<ErrorKind> for Kind {
+            fn from(_: ErrorKind) -> Self {
+                Kind::Unexpected
+            }
+        }
+
+        impl From<ParseIntError> for Kind {
+            fn from(_: ParseIntError) -> Self {
+                Kind::InvalidNumber
+            }
+        }
+
+        fn bytes(input: &str) -> Result<Vec<u8>, ParseError<Kind>> {
+            all_consuming(separated_list1(multispace1, number))(input)
+                .map(|(_, v)| v)
+                .map_err(|e| ParseError::from_nom(input, e))
+        }
+
+        #[test]
+        fn located_nom_errors() {
+            assert_eq!(bytes("1 2 3"), Ok(vec![1, 2, 3]));
+
+            let e = bytes("1 2\n3 256 4").unwrap_err();
+            assert_eq!((e.line, e.column, e.text.as_str()), (2, 3, "256"));
+            assert_eq!(e.kind, Kind::InvalidNumber);
+
+            let e = bytes("1 2 x").unwrap_err();
+            assert_eq!((e.line, e.column, e.text.as_str()), (1, 4, " "));
+            assert_eq!(e.kind, Kind::Unexpected);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Unexpected;
+
+    impl Display for Unexpected {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "unexpected input")
+        }
+    }
+
+    #[test]
+    fn locates_slices() {
+        let input = "ab\ncdé fg\n";
+        let e = ParseError::at(input, &input[8..10], Unexpected);
+        assert_eq!((e.line, e.column, e.text.as_str()), (2, 5, "fg"));
+        assert_eq!(e.to_string(), "line 2, column 5: unexpected input `fg`");
+
+        let e = ParseError::at(input, &input[..0], Unexpected);
+        assert_eq!((e.line, e.column), (1, 1));
+        let e = ParseError::at(input, &input[11..], Unexpected);
+        assert_eq!((e.line, e.column), (3, 1));
+        assert_eq!(
+            e.to_string(),
+            "line 3, column 1: unexpected input at end of line"
+        );
+    }
+
+    #[test]
+    fn tokens() {
+        assert_eq!(token("purple, 3 red"), "purple");
+        assert_eq!(token("Z2345 765"), "Z2345");
+        assert_eq!(token(", 3 red"), ",");
+        assert_eq!(token(""), "");
+    }
+}